@@ -0,0 +1,44 @@
+use crate::{Clock, Instant};
+use core::future::Future;
+use core::time::Duration;
+
+/// A [`Clock`] that can be waited on asynchronously.
+///
+/// The method names match the blocking [`Clock::sleep_until`] and
+/// [`ClockExt::sleep`](crate::ClockExt::sleep); with both traits in scope,
+/// call these as `AsyncClock::sleep(&clock, duration)`.
+pub trait AsyncClock: Clock {
+    fn sleep_until(&self, deadline: Instant) -> impl Future<Output = ()> + Send;
+
+    #[inline]
+    fn sleep(&self, duration: Duration) -> impl Future<Output = ()> + Send {
+        AsyncClock::sleep_until(self, self.now() + duration)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl AsyncClock for crate::StdClock {
+    #[inline]
+    async fn sleep_until(&self, deadline: Instant) {
+        tokio::time::sleep_until(tokio::time::Instant::from_std(deadline.inner)).await
+    }
+}
+
+#[cfg(all(test, feature = "tokio"))]
+mod tests {
+    use super::*;
+    use crate::{ClockExt, StdClock};
+
+    #[test]
+    fn std_clock_sleeps_on_tokio() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+
+        let start = StdClock.now();
+        runtime.block_on(AsyncClock::sleep(&StdClock, Duration::from_millis(5)));
+
+        assert!(StdClock.elapsed_since(start) >= Duration::from_millis(5));
+    }
+}
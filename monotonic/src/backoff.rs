@@ -0,0 +1,136 @@
+use crate::{Clock, ClockExt};
+use std::fmt;
+use std::time::Duration;
+
+/// Exponential backoff delays starting at `base` and doubling up to `max`.
+pub struct Backoff<C>
+where
+    C: Clock,
+{
+    clock: C,
+    base: Duration,
+    max: Duration,
+    current: Duration,
+    jitter: Option<Box<dyn FnMut() -> f64 + Send>>,
+}
+
+impl<C> Backoff<C>
+where
+    C: Clock,
+{
+    #[inline]
+    pub fn new(clock: C, base: Duration, max: Duration) -> Self {
+        Self {
+            clock,
+            base,
+            max,
+            current: base.min(max),
+            jitter: None,
+        }
+    }
+
+    /// Applies full jitter: each delay is scaled by a sample from `rng`,
+    /// which should yield values in `0.0..=1.0` (others are clamped).
+    #[inline]
+    pub fn with_jitter<R>(mut self, rng: R) -> Self
+    where
+        R: FnMut() -> f64 + Send + 'static,
+    {
+        self.jitter = Some(Box::new(rng));
+        self
+    }
+
+    #[inline]
+    pub fn reset(&mut self) {
+        self.current = self.base.min(self.max);
+    }
+
+    #[inline]
+    pub fn next_delay(&mut self) -> Duration {
+        let delay = self.current;
+        self.current = delay.saturating_mul(2).min(self.max);
+
+        match &mut self.jitter {
+            Some(rng) => {
+                let sample = rng();
+                delay.mul_f64(if sample.is_nan() {
+                    0.0
+                } else {
+                    sample.clamp(0.0, 1.0)
+                })
+            }
+            None => delay,
+        }
+    }
+
+    /// Sleeps on the clock for the next delay and returns it.
+    #[inline]
+    pub fn sleep_next(&mut self) -> Duration {
+        let delay = self.next_delay();
+        self.clock.sleep(delay);
+        delay
+    }
+}
+
+impl<C> fmt::Debug for Backoff<C>
+where
+    C: Clock + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Backoff")
+            .field("clock", &self.clock)
+            .field("base", &self.base)
+            .field("max", &self.max)
+            .field("current", &self.current)
+            .field("jitter", &self.jitter.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StdClock;
+    use crate::testing::ManualClock;
+
+    #[test]
+    fn doubles_up_to_max() {
+        let mut backoff = Backoff::new(
+            StdClock,
+            Duration::from_millis(100),
+            Duration::from_millis(500),
+        );
+
+        let delays: Vec<_> = (0..5).map(|_| backoff.next_delay()).collect();
+        assert_eq!(delays, [100, 200, 400, 500, 500].map(Duration::from_millis));
+
+        backoff.reset();
+        assert_eq!(backoff.next_delay(), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn sleeps_on_the_clock() {
+        let clock = ManualClock::new();
+        let start = clock.now();
+        let mut backoff = Backoff::new(
+            &clock as &dyn Clock,
+            Duration::from_secs(1),
+            Duration::from_secs(60),
+        );
+
+        for _ in 0..3 {
+            backoff.sleep_next();
+        }
+
+        assert_eq!(clock.elapsed_since(start), Duration::from_secs(7));
+    }
+
+    #[test]
+    fn jitter_scales_delays() {
+        let mut backoff = Backoff::new(StdClock, Duration::from_secs(1), Duration::from_secs(8))
+            .with_jitter(|| 0.5);
+
+        assert_eq!(backoff.next_delay(), Duration::from_millis(500));
+        assert_eq!(backoff.next_delay(), Duration::from_secs(1));
+    }
+}
@@ -0,0 +1,71 @@
+use crate::DurationExt;
+use std::time::Duration;
+
+/// Per-iteration timings collected by [`ClockExt::bench`](crate::ClockExt::bench).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchStats {
+    pub iters: usize,
+    pub min: Duration,
+    /// The middle sample, or the mean of the two middle samples for an even
+    /// number of iterations.
+    pub median: Duration,
+    pub max: Duration,
+    /// The sum of all samples, saturating at [`Duration::MAX`].
+    pub total: Duration,
+}
+
+impl BenchStats {
+    pub(crate) fn from_samples(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "cannot benchmark zero iterations");
+
+        samples.sort_unstable();
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            let (low, high) = (samples[mid - 1], samples[mid]);
+            low + (high - low) / 2
+        } else {
+            samples[mid]
+        };
+
+        Self {
+            iters: samples.len(),
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+            total: samples.iter().copied().total(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ClockExt;
+    use crate::testing::ManualClock;
+    use std::time::Duration;
+
+    #[test]
+    fn aggregates_samples() {
+        let clock = ManualClock::new();
+        let mut step = 0;
+
+        let stats = clock.bench(4, || {
+            step += 1;
+            clock.advance(Duration::from_millis([30, 10, 40, 20][step - 1]));
+        });
+
+        assert_eq!(stats.iters, 4);
+        assert_eq!(stats.min, Duration::from_millis(10));
+        assert_eq!(stats.median, Duration::from_millis(25));
+        assert_eq!(stats.max, Duration::from_millis(40));
+        assert_eq!(stats.total, Duration::from_millis(100));
+    }
+
+    #[test]
+    fn huge_samples_do_not_overflow() {
+        let mut samples = [Duration::MAX, Duration::MAX - Duration::from_secs(2)];
+        let stats = super::BenchStats::from_samples(&mut samples);
+
+        assert_eq!(stats.median, Duration::MAX - Duration::from_secs(1));
+        assert_eq!(stats.total, Duration::MAX);
+    }
+}
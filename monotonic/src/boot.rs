@@ -0,0 +1,277 @@
+//! Instants that stay comparable across process restarts, on Linux.
+//!
+//! An [`Instant`] is only meaningful within the process that read it. A
+//! [`BootInstant`] instead records the approximate time since the machine
+//! booted, along with the kernel's boot id, so it can be persisted and
+//! compared by a later process. After a reboot the boot id changes, and
+//! combining instants from different boots fails the same way combining
+//! instants from different clock sources does.
+//!
+//! The time since boot comes from `/proc/uptime`, read once per process, plus
+//! the monotonic time elapsed since then. It is only accurate to about 10ms
+//! between processes, and unlike [`Instant`] counts time spent suspended up
+//! to that first read.
+
+use crate::{Clock, Instant, StdClock};
+use core::cmp::Ordering;
+use std::fmt;
+use std::io;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+const BOOT_ID_PATH: &str = "/proc/sys/kernel/random/boot_id";
+const UPTIME_PATH: &str = "/proc/uptime";
+
+/// The kernel's random identifier for the current boot.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct BootId(u128);
+
+impl BootId {
+    #[inline]
+    pub fn current() -> io::Result<BootId> {
+        anchor().map(|anchor| anchor.boot_id)
+    }
+
+    #[inline]
+    pub const fn from_u128(id: u128) -> Self {
+        Self(id)
+    }
+
+    #[inline]
+    pub const fn as_u128(&self) -> u128 {
+        self.0
+    }
+
+    fn parse(uuid: &str) -> Option<Self> {
+        let hex: String = uuid.trim().chars().filter(|&c| c != '-').collect();
+        if hex.len() != 32 {
+            return None;
+        }
+
+        u128::from_str_radix(&hex, 16).ok().map(Self)
+    }
+}
+
+/// Formats the id as a UUID, the way the kernel reports it.
+impl fmt::Display for BootId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hex = format!("{:032x}", self.0);
+        write!(
+            f,
+            "{}-{}-{}-{}-{}",
+            &hex[..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..]
+        )
+    }
+}
+
+/// Two [`BootInstant`]s from different boots were combined or compared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BootMismatch {
+    left: BootId,
+    right: BootId,
+}
+
+impl BootMismatch {
+    /// Returns the boot ids of the two instants, in operand order.
+    #[inline]
+    pub const fn boot_ids(&self) -> (BootId, BootId) {
+        (self.left, self.right)
+    }
+}
+
+impl fmt::Display for BootMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "instants from different boots cannot be combined or compared ({} vs {})",
+            self.left, self.right
+        )
+    }
+}
+
+impl std::error::Error for BootMismatch {}
+
+#[derive(Debug)]
+struct Anchor {
+    boot_id: BootId,
+    instant: Instant,
+    since_boot: Duration,
+}
+
+static ANCHOR: OnceLock<Anchor> = OnceLock::new();
+
+fn anchor() -> io::Result<&'static Anchor> {
+    if let Some(anchor) = ANCHOR.get() {
+        return Ok(anchor);
+    }
+
+    let invalid = |what| io::Error::new(io::ErrorKind::InvalidData, what);
+    let boot_id = BootId::parse(&std::fs::read_to_string(BOOT_ID_PATH)?)
+        .ok_or_else(|| invalid("malformed boot id"))?;
+    let uptime = std::fs::read_to_string(UPTIME_PATH)?;
+    let since_boot = uptime
+        .split_whitespace()
+        .next()
+        .and_then(|secs| secs.parse().ok())
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .ok_or_else(|| invalid("malformed uptime"))?;
+
+    Ok(ANCHOR.get_or_init(|| Anchor {
+        boot_id,
+        instant: StdClock.now(),
+        since_boot,
+    }))
+}
+
+/// A reading of the time since boot, tagged with the boot it was taken in.
+///
+/// See the [module documentation](self) for how accurate it is.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct BootInstant {
+    boot_id: BootId,
+    since_boot: Duration,
+}
+
+impl BootInstant {
+    #[inline]
+    pub fn now() -> io::Result<Self> {
+        Self::from_instant(StdClock.now())
+    }
+
+    /// Converts an instant read from [`StdClock`] in this process.
+    #[inline]
+    pub fn from_instant(instant: Instant) -> io::Result<Self> {
+        let anchor = anchor()?;
+        let since_boot = match instant.checked_duration_since(anchor.instant) {
+            Some(after) => anchor.since_boot + after,
+            None => anchor
+                .since_boot
+                .saturating_sub(anchor.instant.duration_since(instant)),
+        };
+
+        Ok(Self {
+            boot_id: anchor.boot_id,
+            since_boot,
+        })
+    }
+
+    /// Reassembles an instant persisted as its [`boot_id`](Self::boot_id)
+    /// and [`since_boot`](Self::since_boot).
+    #[inline]
+    pub const fn from_parts(boot_id: BootId, since_boot: Duration) -> Self {
+        Self {
+            boot_id,
+            since_boot,
+        }
+    }
+
+    #[inline]
+    pub const fn boot_id(&self) -> BootId {
+        self.boot_id
+    }
+
+    #[inline]
+    pub const fn since_boot(&self) -> Duration {
+        self.since_boot
+    }
+
+    /// Converts back to an [`Instant`] on [`StdClock`], or returns `None` if
+    /// this instant is from another boot or the boot id can't be read.
+    #[inline]
+    pub fn to_instant(&self) -> Option<Instant> {
+        let anchor = anchor().ok()?;
+        if anchor.boot_id != self.boot_id {
+            return None;
+        }
+
+        match self.since_boot.checked_sub(anchor.since_boot) {
+            Some(after) => anchor.instant.checked_add(after),
+            None => anchor
+                .instant
+                .checked_sub(anchor.since_boot - self.since_boot),
+        }
+    }
+
+    /// Returns the time from `earlier` to `self`, or zero if `earlier` is
+    /// later, or an error if they are from different boots.
+    #[inline]
+    pub fn try_duration_since(&self, earlier: BootInstant) -> Result<Duration, BootMismatch> {
+        self.check_same_boot(&earlier)?;
+
+        Ok(self.since_boot.saturating_sub(earlier.since_boot))
+    }
+
+    /// Like [`try_duration_since`](Self::try_duration_since), but panics if
+    /// the instants are from different boots.
+    #[inline]
+    pub fn duration_since(&self, earlier: BootInstant) -> Duration {
+        self.try_duration_since(earlier)
+            .unwrap_or_else(|mismatch| panic!("{mismatch}"))
+    }
+
+    #[inline]
+    pub fn try_cmp(&self, other: &BootInstant) -> Result<Ordering, BootMismatch> {
+        self.check_same_boot(other)?;
+
+        Ok(self.since_boot.cmp(&other.since_boot))
+    }
+
+    fn check_same_boot(&self, other: &BootInstant) -> Result<(), BootMismatch> {
+        if self.boot_id == other.boot_id {
+            return Ok(());
+        }
+
+        Err(BootMismatch {
+            left: self.boot_id,
+            right: other.boot_id,
+        })
+    }
+}
+
+/// Instants from different boots are unordered.
+impl PartialOrd for BootInstant {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.try_cmp(other).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn boot_id_round_trips_through_display() {
+        let uuid = "3061e3a4-2ba9-4d2a-ac54-8a424a9cb78f";
+        let id = BootId::parse(&format!("{uuid}\n")).unwrap();
+
+        assert_eq!(id.to_string(), uuid);
+        assert_eq!(BootId::parse("3061e3a4"), None);
+    }
+
+    #[test]
+    fn round_trips_within_a_boot() {
+        let instant = StdClock.now();
+        let boot = BootInstant::from_instant(instant).unwrap();
+        let later = BootInstant::from_instant(instant + Duration::from_secs(2)).unwrap();
+
+        assert_eq!(boot.to_instant(), Some(instant));
+        assert_eq!(later.duration_since(boot), Duration::from_secs(2));
+        assert!(boot < later);
+    }
+
+    #[test]
+    fn different_boots_do_not_compare() {
+        let before = BootInstant::from_parts(BootId::from_u128(1), Duration::from_secs(10));
+        let after = BootInstant::from_parts(BootId::from_u128(2), Duration::from_secs(20));
+
+        let mismatch = after.try_duration_since(before).unwrap_err();
+        assert_eq!(mismatch.boot_ids(), (after.boot_id(), before.boot_id()));
+        assert_eq!(before.partial_cmp(&after), None);
+        assert_eq!(after.to_instant(), None);
+    }
+}
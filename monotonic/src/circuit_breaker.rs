@@ -0,0 +1,158 @@
+use crate::{Clock, Instant};
+use std::sync::Mutex;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    Closed,
+    Open,
+    HalfOpen,
+}
+
+#[derive(Debug)]
+enum State {
+    Closed { failures: u32 },
+    Open { since: Instant },
+    HalfOpen { probing: bool },
+}
+
+/// Stops calls to a failing dependency, then lets a single probe through once
+/// `reset_timeout` has passed.
+///
+/// The breaker opens after `failure_threshold` consecutive failures. Once
+/// `reset_timeout` has elapsed on the clock it half-opens, and
+/// [`allow`](Self::allow) lets exactly one call through: its success closes
+/// the breaker again, its failure reopens it.
+#[derive(Debug)]
+pub struct CircuitBreaker<C>
+where
+    C: Clock,
+{
+    clock: C,
+    failure_threshold: u32,
+    reset_timeout: Duration,
+    state: Mutex<State>,
+}
+
+impl<C> CircuitBreaker<C>
+where
+    C: Clock,
+{
+    /// # Panics
+    ///
+    /// Panics if `failure_threshold` is zero.
+    #[inline]
+    pub fn new(clock: C, failure_threshold: u32, reset_timeout: Duration) -> Self {
+        assert!(
+            failure_threshold > 0,
+            "CircuitBreaker failure_threshold must be non-zero"
+        );
+
+        Self {
+            clock,
+            failure_threshold,
+            reset_timeout,
+            state: Mutex::new(State::Closed { failures: 0 }),
+        }
+    }
+
+    #[inline]
+    pub fn state(&self) -> CircuitState {
+        let mut state = self.state.lock().unwrap();
+        self.half_open_if_due(&mut state);
+
+        match *state {
+            State::Closed { .. } => CircuitState::Closed,
+            State::Open { .. } => CircuitState::Open,
+            State::HalfOpen { .. } => CircuitState::HalfOpen,
+        }
+    }
+
+    /// Returns whether a call may go ahead, and should then be followed by
+    /// [`record_success`](Self::record_success) or
+    /// [`record_failure`](Self::record_failure).
+    #[inline]
+    pub fn allow(&self) -> bool {
+        let mut state = self.state.lock().unwrap();
+        self.half_open_if_due(&mut state);
+
+        match &mut *state {
+            State::Closed { .. } => true,
+            State::Open { .. } => false,
+            State::HalfOpen { probing } => !std::mem::replace(probing, true),
+        }
+    }
+
+    #[inline]
+    pub fn record_success(&self) {
+        *self.state.lock().unwrap() = State::Closed { failures: 0 };
+    }
+
+    #[inline]
+    pub fn record_failure(&self) {
+        let mut state = self.state.lock().unwrap();
+
+        let open = match &mut *state {
+            State::Closed { failures } => {
+                *failures += 1;
+                *failures >= self.failure_threshold
+            }
+            State::Open { .. } => false,
+            State::HalfOpen { .. } => true,
+        };
+        if open {
+            *state = State::Open {
+                since: self.clock.now(),
+            };
+        }
+    }
+
+    fn half_open_if_due(&self, state: &mut State) {
+        if let State::Open { since } = *state
+            && self.clock.now().saturating_duration_since(since) >= self.reset_timeout
+        {
+            *state = State::HalfOpen { probing: false };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ManualClock;
+
+    #[test]
+    fn half_opens_after_reset_timeout() {
+        let clock = ManualClock::new();
+        let breaker = CircuitBreaker::new(&clock, 2, Duration::from_secs(5));
+
+        breaker.record_failure();
+        assert!(breaker.allow());
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(!breaker.allow());
+
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        assert!(breaker.allow());
+        assert!(!breaker.allow());
+
+        breaker.record_success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn failed_probe_reopens() {
+        let clock = ManualClock::new();
+        let breaker = CircuitBreaker::new(&clock, 1, Duration::from_secs(5));
+
+        breaker.record_failure();
+        clock.advance(Duration::from_secs(5));
+        assert!(breaker.allow());
+
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::Open);
+        clock.advance(Duration::from_secs(4));
+        assert!(!breaker.allow());
+    }
+}
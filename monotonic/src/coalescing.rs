@@ -0,0 +1,123 @@
+use crate::{Clock, Instant};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// How [`CoalescingTimer::schedule`] merges with an already pending deadline.
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq)]
+pub enum Coalesce {
+    /// Keep the later of the two deadlines.
+    #[default]
+    Latest,
+    /// Keep the earlier of the two deadlines.
+    Earliest,
+}
+
+/// Merges any number of scheduling requests into a single pending deadline
+/// that fires once.
+#[derive(Debug)]
+pub struct CoalescingTimer<C>
+where
+    C: Clock,
+{
+    clock: C,
+    coalesce: Coalesce,
+    deadline: Mutex<Option<Instant>>,
+}
+
+impl<C> CoalescingTimer<C>
+where
+    C: Clock,
+{
+    #[inline]
+    pub fn new(clock: C) -> Self {
+        Self::with_coalesce(clock, Coalesce::default())
+    }
+
+    #[inline]
+    pub fn with_coalesce(clock: C, coalesce: Coalesce) -> Self {
+        Self {
+            clock,
+            coalesce,
+            deadline: Mutex::new(None),
+        }
+    }
+
+    #[inline]
+    pub const fn coalesce(&self) -> Coalesce {
+        self.coalesce
+    }
+
+    #[inline]
+    pub fn deadline(&self) -> Option<Instant> {
+        *self.deadline.lock().unwrap()
+    }
+
+    #[inline]
+    pub fn schedule(&self, delay: Duration) {
+        let requested = self.clock.now().saturating_add(delay);
+        let mut deadline = self.deadline.lock().unwrap();
+        *deadline = Some(match (*deadline, self.coalesce) {
+            (None, _) => requested,
+            (Some(pending), Coalesce::Latest) => pending.max(requested),
+            (Some(pending), Coalesce::Earliest) => pending.min(requested),
+        });
+    }
+
+    #[inline]
+    pub fn cancel(&self) {
+        *self.deadline.lock().unwrap() = None;
+    }
+
+    /// Returns `true`, once, when the pending deadline has been reached.
+    #[inline]
+    pub fn poll(&self) -> bool {
+        let now = self.clock.now();
+        let mut deadline = self.deadline.lock().unwrap();
+        if deadline.is_some_and(|deadline| now >= deadline) {
+            *deadline = None;
+            return true;
+        }
+
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ManualClock;
+    use std::sync::Arc;
+
+    #[test]
+    fn many_schedules_fire_once() {
+        let clock = Arc::new(ManualClock::new());
+        let timer = CoalescingTimer::new(Arc::clone(&clock));
+        assert!(!timer.poll());
+
+        for delay in [10, 30, 20] {
+            timer.schedule(Duration::from_millis(delay));
+        }
+        clock.advance(Duration::from_millis(29));
+        assert!(!timer.poll());
+
+        clock.advance(Duration::from_millis(1));
+        assert!(timer.poll());
+        assert!(!timer.poll());
+    }
+
+    #[test]
+    fn earliest_keeps_first_deadline() {
+        let clock = Arc::new(ManualClock::new());
+        let timer = CoalescingTimer::with_coalesce(Arc::clone(&clock), Coalesce::Earliest);
+
+        timer.schedule(Duration::from_millis(30));
+        timer.schedule(Duration::from_millis(10));
+        clock.advance(Duration::from_millis(10));
+        assert!(timer.poll());
+
+        timer.schedule(Duration::from_millis(5));
+        timer.cancel();
+        clock.advance(Duration::from_millis(5));
+        assert!(!timer.poll());
+    }
+}
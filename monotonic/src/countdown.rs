@@ -0,0 +1,87 @@
+use crate::{Clock, ClockExt, Instant};
+use core::time::Duration;
+
+/// A fixed-length countdown that reports how far along it is.
+#[derive(Debug)]
+pub struct CountdownTimer<C>
+where
+    C: Clock,
+{
+    clock: C,
+    start: Instant,
+    total: Duration,
+}
+
+impl<C> CountdownTimer<C>
+where
+    C: Clock,
+{
+    #[inline]
+    pub fn new(clock: C, total: Duration) -> Self {
+        Self {
+            start: clock.now(),
+            clock,
+            total,
+        }
+    }
+
+    #[inline]
+    pub const fn total(&self) -> Duration {
+        self.total
+    }
+
+    #[inline]
+    pub fn remaining(&self) -> Duration {
+        self.total
+            .saturating_sub(self.clock.elapsed_since(self.start))
+    }
+
+    #[inline]
+    pub fn is_done(&self) -> bool {
+        self.remaining().is_zero()
+    }
+
+    /// Returns the elapsed fraction of the total, from `0.0` to `1.0`.
+    ///
+    /// A zero-length countdown is always `1.0`.
+    #[inline]
+    pub fn progress(&self) -> f64 {
+        if self.total.is_zero() {
+            return 1.0;
+        }
+
+        (self.clock.elapsed_since(self.start).as_secs_f64() / self.total.as_secs_f64()).min(1.0)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::testing::ManualClock;
+    use std::sync::Arc;
+
+    #[test]
+    fn progresses_to_done() {
+        let clock = Arc::new(ManualClock::new());
+        let countdown = CountdownTimer::new(Arc::clone(&clock), Duration::from_secs(10));
+        assert_eq!(countdown.progress(), 0.0);
+
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(countdown.progress(), 0.5);
+        assert_eq!(countdown.remaining(), Duration::from_secs(5));
+        assert!(!countdown.is_done());
+
+        clock.advance(Duration::from_secs(6));
+        assert_eq!(countdown.progress(), 1.0);
+        assert_eq!(countdown.remaining(), Duration::ZERO);
+        assert!(countdown.is_done());
+    }
+
+    #[test]
+    fn zero_length_is_done() {
+        let countdown = CountdownTimer::new(ManualClock::new(), Duration::ZERO);
+
+        assert!(countdown.is_done());
+        assert_eq!(countdown.progress(), 1.0);
+    }
+}
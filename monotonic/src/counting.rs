@@ -0,0 +1,73 @@
+use crate::{Clock, Instant};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Counts how many times `inner` is read.
+///
+/// A lighter alternative to [`ObservableClock`](crate::ObservableClock) when
+/// only the number of reads matters.
+#[derive(Debug)]
+pub struct CountingClock<C>
+where
+    C: Clock,
+{
+    inner: C,
+    count: AtomicU64,
+}
+
+impl<C> CountingClock<C>
+where
+    C: Clock,
+{
+    #[inline]
+    pub fn new(inner: C) -> Self {
+        Self {
+            inner,
+            count: AtomicU64::new(0),
+        }
+    }
+
+    #[inline]
+    pub fn count(&self) -> u64 {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    #[inline]
+    pub fn reset(&self) {
+        self.count.store(0, Ordering::Relaxed);
+    }
+}
+
+impl<C> Clock for CountingClock<C>
+where
+    C: Clock,
+{
+    #[inline]
+    fn now(&self) -> Instant {
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.inner.now()
+    }
+
+    #[inline]
+    fn sleep_until(&self, deadline: Instant) {
+        self.inner.sleep_until(deadline);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StdClock;
+
+    #[test]
+    fn counts_reads() {
+        let clock = CountingClock::new(StdClock);
+
+        (0..3).for_each(|_| {
+            clock.now();
+        });
+        assert_eq!(clock.count(), 3);
+
+        clock.reset();
+        assert_eq!(clock.count(), 0);
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{Clock, Instant};
+use core::time::Duration;
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Deadline {
+    instant: Instant,
+}
+
+impl Deadline {
+    #[inline]
+    pub const fn at(instant: Instant) -> Self {
+        Self { instant }
+    }
+
+    #[inline]
+    pub fn after<C>(clock: &C, duration: Duration) -> Self
+    where
+        C: Clock + ?Sized,
+    {
+        Self::at(clock.now() + duration)
+    }
+
+    #[inline]
+    pub const fn instant(&self) -> Instant {
+        self.instant
+    }
+
+    #[inline]
+    pub fn is_expired<C>(&self, clock: &C) -> bool
+    where
+        C: Clock + ?Sized,
+    {
+        clock.now() >= self.instant
+    }
+
+    #[inline]
+    pub fn remaining<C>(&self, clock: &C) -> Duration
+    where
+        C: Clock + ?Sized,
+    {
+        self.instant.saturating_duration_since(clock.now())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::testing::ManualClock;
+
+    #[test]
+    fn expires_at_the_deadline() {
+        let clock = ManualClock::new();
+        let deadline = Deadline::after(&clock, Duration::from_secs(2));
+
+        assert!(!deadline.is_expired(&clock));
+        assert_eq!(deadline.remaining(&clock), Duration::from_secs(2));
+
+        clock.advance(Duration::from_secs(2));
+        assert!(deadline.is_expired(&clock));
+        assert_eq!(deadline.remaining(&clock), Duration::ZERO);
+
+        clock.advance(Duration::from_secs(1));
+        assert!(deadline.is_expired(&clock));
+        assert_eq!(deadline.remaining(&clock), Duration::ZERO);
+    }
+
+    #[test]
+    fn at_fixed_instant() {
+        let clock = ManualClock::new();
+        let instant = clock.now() + Duration::from_millis(10);
+
+        let deadline = Deadline::at(instant);
+        assert_eq!(deadline.instant(), instant);
+        assert_eq!(deadline.remaining(&clock), Duration::from_millis(10));
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{Clock, Instant};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Lets an event through at most once per `window`.
+///
+/// The first call is always allowed; later calls are allowed once `window`
+/// has elapsed since the last allowed one.
+#[derive(Debug)]
+pub struct DebounceGate<C>
+where
+    C: Clock,
+{
+    clock: C,
+    window: Duration,
+    last_allowed: Mutex<Option<Instant>>,
+}
+
+impl<C> DebounceGate<C>
+where
+    C: Clock,
+{
+    #[inline]
+    pub fn new(clock: C, window: Duration) -> Self {
+        Self {
+            clock,
+            window,
+            last_allowed: Mutex::new(None),
+        }
+    }
+
+    #[inline]
+    pub const fn window(&self) -> Duration {
+        self.window
+    }
+
+    #[inline]
+    pub fn allow(&self) -> bool {
+        let mut last_allowed = self.last_allowed.lock().unwrap();
+
+        let now = self.clock.now();
+        let allowed = match *last_allowed {
+            Some(last) => now.saturating_duration_since(last) >= self.window,
+            None => true,
+        };
+        if allowed {
+            *last_allowed = Some(now);
+        }
+
+        allowed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ManualClock;
+    use std::sync::Arc;
+
+    #[test]
+    fn suppresses_within_window() {
+        let clock = Arc::new(ManualClock::new());
+        let gate = DebounceGate::new(Arc::clone(&clock), Duration::from_secs(1));
+
+        assert!(gate.allow());
+        assert!(!gate.allow());
+
+        clock.advance(Duration::from_secs(1) - Duration::from_nanos(1));
+        assert!(!gate.allow());
+
+        clock.advance(Duration::from_nanos(1));
+        assert!(gate.allow());
+        assert!(!gate.allow());
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{Clock, ClockExt, Instant};
+use core::time::Duration;
+
+/// A captured start instant that can be read back with any handle to the
+/// same clock.
+///
+/// Unlike [`Stopwatch`](crate::Stopwatch), this doesn't store the clock, so
+/// it stays `Copy` and cheap to embed. Reading it with a different clock's
+/// handle panics when clock sources are tracked.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Elapsed {
+    start: Instant,
+}
+
+impl Elapsed {
+    #[inline]
+    pub fn start<C>(clock: &C) -> Self
+    where
+        C: Clock + ?Sized,
+    {
+        Self { start: clock.now() }
+    }
+
+    #[inline]
+    pub const fn started_at(&self) -> Instant {
+        self.start
+    }
+
+    #[inline]
+    pub fn read<C>(&self, clock: &C) -> Duration
+    where
+        C: Clock + ?Sized,
+    {
+        clock.elapsed_since(self.start)
+    }
+}
+
+impl From<Instant> for Elapsed {
+    #[inline]
+    fn from(start: Instant) -> Self {
+        Self { start }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::testing::ManualClock;
+    use std::sync::Arc;
+
+    #[test]
+    fn read_through_any_handle() {
+        let clock = Arc::new(ManualClock::new());
+        let elapsed = Elapsed::start(&clock);
+
+        clock.advance(Duration::from_secs(2));
+        assert_eq!(elapsed.read(&clock), Duration::from_secs(2));
+        assert_eq!(elapsed.read(&*clock), Duration::from_secs(2));
+    }
+
+    #[cfg(any(debug_assertions, feature = "track-source"))]
+    #[test]
+    #[should_panic(expected = "different clock sources")]
+    fn read_with_other_clock_panics() {
+        let elapsed = Elapsed::start(&ManualClock::new());
+
+        elapsed.read(&ManualClock::new());
+    }
+}
@@ -0,0 +1,117 @@
+use core::fmt;
+use core::sync::atomic::{AtomicU8, Ordering};
+
+#[cfg(any(debug_assertions, feature = "track-source"))]
+use crate::ClockSource;
+
+/// Two instants from different clock sources were combined or compared.
+///
+/// Only ever returned when clock sources are tracked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClockDomainMismatch {
+    #[cfg(any(debug_assertions, feature = "track-source"))]
+    pub(crate) left: ClockSource,
+    #[cfg(any(debug_assertions, feature = "track-source"))]
+    pub(crate) right: ClockSource,
+}
+
+impl ClockDomainMismatch {
+    /// Returns the sources of the two instants, in operand order.
+    #[cfg(any(debug_assertions, feature = "track-source"))]
+    #[inline]
+    pub fn sources(&self) -> (ClockSource, ClockSource) {
+        (self.left, self.right)
+    }
+}
+
+impl fmt::Display for ClockDomainMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #[cfg(any(debug_assertions, feature = "track-source"))]
+        return write!(
+            f,
+            "instants from different clock sources cannot be combined or compared ({:?} vs {:?})",
+            self.left, self.right
+        );
+
+        #[cfg(not(any(debug_assertions, feature = "track-source")))]
+        f.write_str("instants from different clock sources cannot be combined or compared")
+    }
+}
+
+impl core::error::Error for ClockDomainMismatch {}
+
+/// What to do when instants from different clock sources are combined or
+/// compared.
+///
+/// Set process-wide with [`set_mismatch_policy`]. The fallible `try_*`
+/// methods on [`Instant`](crate::Instant) always return the mismatch instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(u8)]
+pub enum MismatchPolicy {
+    #[default]
+    Panic,
+    /// Reports the mismatch through `tracing` when that feature is enabled,
+    /// or to standard error otherwise, and carries on. Same as `Ignore` in
+    /// `no_std` builds without `tracing`.
+    Log,
+    Ignore,
+}
+
+impl MismatchPolicy {
+    #[track_caller]
+    #[inline]
+    pub(crate) fn handle(self, mismatch: &ClockDomainMismatch) {
+        match self {
+            MismatchPolicy::Panic => panic!("{mismatch}"),
+            MismatchPolicy::Log => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!("{mismatch}");
+                #[cfg(all(feature = "std", not(feature = "tracing")))]
+                eprintln!("{mismatch}");
+            }
+            MismatchPolicy::Ignore => {}
+        }
+    }
+}
+
+static MISMATCH_POLICY: AtomicU8 = AtomicU8::new(MismatchPolicy::Panic as u8);
+
+/// Sets how clock-domain mismatches are handled for the whole process.
+///
+/// Mismatches are only detected when clock sources are tracked.
+#[inline]
+pub fn set_mismatch_policy(policy: MismatchPolicy) {
+    MISMATCH_POLICY.store(policy as u8, Ordering::Relaxed);
+}
+
+#[inline]
+pub fn mismatch_policy() -> MismatchPolicy {
+    match MISMATCH_POLICY.load(Ordering::Relaxed) {
+        1 => MismatchPolicy::Log,
+        2 => MismatchPolicy::Ignore,
+        _ => MismatchPolicy::Panic,
+    }
+}
+
+#[cfg(all(test, feature = "std", any(debug_assertions, feature = "track-source")))]
+mod tests {
+    use super::*;
+    use crate::ClockSource;
+
+    const MISMATCH: ClockDomainMismatch = ClockDomainMismatch {
+        left: ClockSource::Std,
+        right: ClockSource::Mock(0),
+    };
+
+    #[test]
+    fn log_and_ignore_carry_on() {
+        MismatchPolicy::Log.handle(&MISMATCH);
+        MismatchPolicy::Ignore.handle(&MISMATCH);
+    }
+
+    #[test]
+    #[should_panic(expected = "different clock sources")]
+    fn panic_by_default() {
+        mismatch_policy().handle(&MISMATCH);
+    }
+}
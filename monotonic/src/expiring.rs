@@ -0,0 +1,83 @@
+use crate::{Clock, Instant};
+use core::time::Duration;
+
+/// A value that is only available until its expiry instant.
+///
+/// Like [`TtlCache`](crate::TtlCache) entries, the value is gone once the
+/// clock reaches the expiry instant.
+#[derive(Debug)]
+pub struct Expiring<T, C>
+where
+    C: Clock,
+{
+    clock: C,
+    value: T,
+    expiry: Instant,
+}
+
+impl<T, C> Expiring<T, C>
+where
+    C: Clock,
+{
+    #[inline]
+    pub fn new(clock: C, value: T, ttl: Duration) -> Self {
+        Self {
+            expiry: clock.now().saturating_add(ttl),
+            clock,
+            value,
+        }
+    }
+
+    #[inline]
+    pub fn expiry(&self) -> Instant {
+        self.expiry
+    }
+
+    #[inline]
+    pub fn is_expired(&self) -> bool {
+        self.clock.now() >= self.expiry
+    }
+
+    #[inline]
+    pub fn get(&self) -> Option<&T> {
+        (!self.is_expired()).then_some(&self.value)
+    }
+
+    /// Extends the value's life to `ttl` from now, even if it had expired.
+    #[inline]
+    pub fn refresh(&mut self, ttl: Duration) {
+        self.expiry = self.clock.now().saturating_add(ttl);
+    }
+
+    /// Replaces the value, which then lives for `ttl` from now.
+    #[inline]
+    pub fn set(&mut self, value: T, ttl: Duration) {
+        self.value = value;
+        self.refresh(ttl);
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::testing::ManualClock;
+    use std::sync::Arc;
+
+    #[test]
+    fn vanishes_at_expiry() {
+        let clock = Arc::new(ManualClock::new());
+        let mut token = Expiring::new(Arc::clone(&clock), "secret", Duration::from_secs(60));
+
+        clock.advance(Duration::from_secs(60) - Duration::from_nanos(1));
+        assert_eq!(token.get(), Some(&"secret"));
+
+        clock.advance(Duration::from_nanos(1));
+        assert_eq!(token.get(), None);
+
+        token.refresh(Duration::from_secs(1));
+        assert_eq!(token.get(), Some(&"secret"));
+
+        token.set("rotated", Duration::from_secs(1));
+        assert_eq!(token.get(), Some(&"rotated"));
+    }
+}
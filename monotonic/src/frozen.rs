@@ -0,0 +1,158 @@
+use crate::{Clock, ClockExt, Instant};
+use std::sync::Mutex;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq)]
+pub enum UnfreezeMode {
+    /// Jump straight back to the wrapped clock's reading.
+    #[default]
+    CatchUp,
+    /// Resume from the frozen reading, so the frozen interval is never seen.
+    Continue,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct State {
+    lag: Duration,
+    frozen_at: Option<Instant>,
+}
+
+#[derive(Debug)]
+pub struct FrozenClock<C>
+where
+    C: Clock,
+{
+    clock: C,
+    base: Instant,
+    mode: UnfreezeMode,
+    state: Mutex<State>,
+}
+
+impl<C> FrozenClock<C>
+where
+    C: Clock,
+{
+    #[inline]
+    pub fn new(clock: C) -> Self {
+        Self::with_unfreeze_mode(clock, UnfreezeMode::default())
+    }
+
+    #[inline]
+    pub fn with_unfreeze_mode(clock: C, mode: UnfreezeMode) -> Self {
+        Self {
+            base: clock.now(),
+            clock,
+            mode,
+            state: Mutex::new(State {
+                lag: Duration::ZERO,
+                frozen_at: None,
+            }),
+        }
+    }
+
+    #[inline]
+    pub const fn unfreeze_mode(&self) -> UnfreezeMode {
+        self.mode
+    }
+
+    #[inline]
+    pub fn is_frozen(&self) -> bool {
+        self.state.lock().unwrap().frozen_at.is_some()
+    }
+
+    #[inline]
+    pub fn freeze(&self) {
+        let mut state = self.state.lock().unwrap();
+        if state.frozen_at.is_none() {
+            state.frozen_at = Some(self.passthrough(&state));
+        }
+    }
+
+    #[inline]
+    pub fn unfreeze(&self) {
+        let mut state = self.state.lock().unwrap();
+        let Some(frozen_at) = state.frozen_at.take() else {
+            return;
+        };
+
+        if self.mode == UnfreezeMode::Continue {
+            let frozen_for = self
+                .passthrough(&state)
+                .saturating_duration_since(frozen_at);
+            state.lag += frozen_for;
+        }
+    }
+
+    #[inline]
+    fn passthrough(&self, state: &State) -> Instant {
+        self.base
+            + self
+                .clock
+                .elapsed_since(self.base)
+                .saturating_sub(state.lag)
+    }
+}
+
+impl<C> Clock for FrozenClock<C>
+where
+    C: Clock,
+{
+    #[inline]
+    fn now(&self) -> Instant {
+        let state = self.state.lock().unwrap();
+        state.frozen_at.unwrap_or_else(|| self.passthrough(&state))
+    }
+
+    #[inline]
+    fn sleep_until(&self, deadline: Instant) {
+        loop {
+            let now = self.now();
+            if now >= deadline {
+                return;
+            }
+
+            self.clock.sleep(deadline - now);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ManualClock;
+    use std::sync::Arc;
+
+    #[test]
+    fn catch_up_jumps_to_inner_time() {
+        let inner = Arc::new(ManualClock::new());
+        let clock = FrozenClock::new(Arc::clone(&inner));
+        let start = clock.now();
+
+        clock.freeze();
+        assert!(clock.is_frozen());
+        inner.advance(Duration::from_secs(2));
+        assert_eq!(clock.now(), start);
+
+        clock.unfreeze();
+        assert_eq!(clock.now() - start, Duration::from_secs(2));
+    }
+
+    #[test]
+    fn continue_skips_frozen_interval() {
+        let inner = Arc::new(ManualClock::new());
+        let clock = FrozenClock::with_unfreeze_mode(Arc::clone(&inner), UnfreezeMode::Continue);
+        let start = clock.now();
+
+        inner.advance(Duration::from_secs(1));
+        clock.freeze();
+        inner.advance(Duration::from_secs(2));
+        assert_eq!(clock.now() - start, Duration::from_secs(1));
+
+        clock.unfreeze();
+        assert!(!clock.is_frozen());
+        assert_eq!(clock.now() - start, Duration::from_secs(1));
+
+        inner.advance(Duration::from_secs(1));
+        assert_eq!(clock.now() - start, Duration::from_secs(2));
+    }
+}
@@ -0,0 +1,141 @@
+//! A process-wide default clock.
+//!
+//! Code that cannot have a [`Clock`] threaded through to it can read
+//! [`now`] instead, which defaults to [`StdClock`]. The clock can be replaced
+//! at any time with [`set_global_clock`], usually once at startup or at the
+//! start of a test.
+//!
+//! Because the clock is shared by the whole process, setting it from several
+//! places (or from tests running in parallel) means each caller may observe
+//! the others' clocks. Prefer passing clocks explicitly where possible, and
+//! use [`with_clock`] to override the clock for the current thread only.
+
+use crate::{Clock, Instant, StdClock};
+use std::cell::RefCell;
+use std::sync::{Arc, LazyLock, RwLock};
+
+pub type SharedClock = Arc<dyn Clock + Send + Sync>;
+
+static GLOBAL_CLOCK: LazyLock<RwLock<SharedClock>> =
+    LazyLock::new(|| RwLock::new(Arc::new(StdClock)));
+
+thread_local! {
+    static THREAD_CLOCK: RefCell<Option<SharedClock>> = const { RefCell::new(None) };
+}
+
+#[inline]
+pub fn set_global_clock(clock: SharedClock) {
+    replace_global_clock(clock);
+}
+
+/// Returns the current thread's override installed by [`with_clock`], or the
+/// process-wide clock if there is none.
+#[inline]
+pub fn global_clock() -> SharedClock {
+    THREAD_CLOCK
+        .with_borrow(Option::clone)
+        .unwrap_or_else(|| Arc::clone(&GLOBAL_CLOCK.read().unwrap()))
+}
+
+#[inline]
+pub fn now() -> Instant {
+    global_clock().now()
+}
+
+/// Runs `f` with `clock` overriding the global clock on the current thread.
+///
+/// Other threads are unaffected, so tests can use this in parallel. The
+/// previous override, if any, is restored when `f` returns or panics.
+#[inline]
+pub fn with_clock<T, F>(clock: SharedClock, f: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    struct Restore(Option<SharedClock>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            THREAD_CLOCK.set(self.0.take());
+        }
+    }
+
+    let _restore = Restore(THREAD_CLOCK.replace(Some(clock)));
+    f()
+}
+
+/// Installs `clock` as the global clock until the returned guard is dropped,
+/// at which point the previous clock is restored.
+#[inline]
+pub fn override_global_clock(clock: SharedClock) -> GlobalClockGuard {
+    GlobalClockGuard {
+        previous: Some(replace_global_clock(clock)),
+    }
+}
+
+#[inline]
+fn replace_global_clock(clock: SharedClock) -> SharedClock {
+    std::mem::replace(&mut *GLOBAL_CLOCK.write().unwrap(), clock)
+}
+
+#[must_use = "the previous global clock is restored when the guard is dropped"]
+pub struct GlobalClockGuard {
+    previous: Option<SharedClock>,
+}
+
+impl Drop for GlobalClockGuard {
+    #[inline]
+    fn drop(&mut self) {
+        if let Some(previous) = self.previous.take() {
+            replace_global_clock(previous);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ManualClock;
+    use std::time::Duration;
+
+    #[test]
+    fn thread_local_override() {
+        let mock = Arc::new(ManualClock::new());
+        mock.advance(Duration::from_secs(5));
+
+        let mock_ptr = Arc::as_ptr(&mock).cast::<()>();
+        let observed = with_clock(mock.clone(), || {
+            let other_thread = std::thread::spawn(global_clock).join().unwrap();
+            assert_ne!(Arc::as_ptr(&other_thread).cast::<()>(), mock_ptr);
+
+            let nested = Arc::new(ManualClock::new());
+            with_clock(nested.clone(), || assert_eq!(now(), nested.now()));
+
+            now()
+        });
+        assert_eq!(observed, mock.now());
+
+        let result = std::panic::catch_unwind(|| with_clock(mock.clone(), || panic!("boom")));
+        assert!(result.is_err());
+        assert!(THREAD_CLOCK.with_borrow(Option::is_none));
+    }
+
+    #[test]
+    fn override_and_restore() {
+        let before = now();
+        assert!(global_clock().now() >= before);
+
+        let mock = Arc::new(ManualClock::new());
+        {
+            let _guard = override_global_clock(mock.clone());
+            let start = now();
+            mock.advance(Duration::from_secs(1));
+            assert_eq!(now() - start, Duration::from_secs(1));
+        }
+        assert!(now() >= before);
+
+        set_global_clock(mock.clone());
+        assert_eq!(now(), mock.now());
+        set_global_clock(Arc::new(StdClock));
+        assert!(now() >= before);
+    }
+}
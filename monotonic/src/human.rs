@@ -0,0 +1,134 @@
+use core::fmt;
+use core::time::Duration;
+
+/// Displays a [`Duration`] compactly, e.g. `250ms`, `1.5s` or `3m4s`.
+///
+/// Durations under a minute are shown in the largest fitting unit out of
+/// `ns`, `µs`, `ms` and `s`, rounded to three significant digits. Longer
+/// durations are shown as whole minutes and seconds, or hours and minutes
+/// from an hour up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HumanDuration(pub Duration);
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for core::time::Duration {}
+}
+
+pub trait HumanDurationExt: private::Sealed {
+    fn human(&self) -> HumanDuration;
+}
+
+impl HumanDurationExt for Duration {
+    #[inline]
+    fn human(&self) -> HumanDuration {
+        HumanDuration(*self)
+    }
+}
+
+/// An offset between two instants, shown as a [`HumanDuration`] with a
+/// leading `-` when negative.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RelativeOffset {
+    pub(crate) offset: Duration,
+    pub(crate) negative: bool,
+}
+
+impl fmt::Display for RelativeOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.negative {
+            f.write_str("-")?;
+        }
+        HumanDuration(self.offset).fmt(f)
+    }
+}
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+const UNITS: [(u128, &str); 3] = [(1_000, "µs"), (1_000_000, "ms"), (NANOS_PER_SEC, "s")];
+
+impl fmt::Display for HumanDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nanos = self.0.as_nanos();
+        if nanos < 1_000 {
+            return write!(f, "{nanos}ns");
+        }
+
+        for (i, (scale, unit)) in UNITS.into_iter().enumerate() {
+            let mut places = if nanos < 10 * scale {
+                2
+            } else if nanos < 100 * scale {
+                1
+            } else {
+                0
+            };
+            let mut mantissa = (nanos * 10u128.pow(places) + scale / 2) / scale;
+
+            let limit = if i + 1 < UNITS.len() { 1_000 } else { 60 };
+            if mantissa >= limit * 10u128.pow(places) {
+                continue;
+            }
+
+            while places > 0 && mantissa.is_multiple_of(10) {
+                mantissa /= 10;
+                places -= 1;
+            }
+            let divisor = 10u128.pow(places);
+            return if places == 0 {
+                write!(f, "{mantissa}{unit}")
+            } else {
+                let width = places as usize;
+                write!(
+                    f,
+                    "{}.{:0width$}{unit}",
+                    mantissa / divisor,
+                    mantissa % divisor
+                )
+            };
+        }
+
+        let secs = (nanos + NANOS_PER_SEC / 2) / NANOS_PER_SEC;
+        let (major, minor, units) = if secs < 3600 {
+            (secs / 60, secs % 60, ("m", "s"))
+        } else {
+            let mins = (secs + 30) / 60;
+            (mins / 60, mins % 60, ("h", "m"))
+        };
+        if minor == 0 {
+            write!(f, "{major}{}", units.0)
+        } else {
+            write!(f, "{major}{}{minor}{}", units.0, units.1)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    fn human(duration: Duration) -> String {
+        duration.human().to_string()
+    }
+
+    #[test]
+    fn picks_scale() {
+        assert_eq!(human(Duration::ZERO), "0ns");
+        assert_eq!(human(Duration::from_nanos(999)), "999ns");
+        assert_eq!(human(Duration::from_nanos(1_500)), "1.5µs");
+        assert_eq!(human(Duration::from_millis(250)), "250ms");
+        assert_eq!(human(Duration::from_micros(12_345)), "12.3ms");
+        assert_eq!(human(Duration::from_millis(1_500)), "1.5s");
+        assert_eq!(human(Duration::from_secs(184)), "3m4s");
+        assert_eq!(human(Duration::from_secs(120)), "2m");
+        assert_eq!(human(Duration::from_secs(3 * 3600 + 125)), "3h2m");
+    }
+
+    #[test]
+    fn rounding_carries_into_next_unit() {
+        assert_eq!(human(Duration::from_nanos(999_999)), "1ms");
+        assert_eq!(human(Duration::from_nanos(59_999_000_000)), "1m");
+        assert_eq!(
+            human(Duration::from_secs(3599) + Duration::from_millis(600)),
+            "1h"
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{Clock, Instant};
+use core::cell::Cell;
+use core::time::Duration;
+
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq)]
+pub enum MissedTickBehavior {
+    /// Yield every missed tick, one per call, until caught up.
+    #[default]
+    Burst,
+    /// Yield the most recent missed tick once and drop the rest.
+    Skip,
+}
+
+/// A periodic ticker whose first tick falls one period after creation.
+///
+/// The next tick is kept in a [`Cell`] so that [`poll_tick`](Self::poll_tick)
+/// can take `&self`, which makes `Interval` `!Sync`.
+#[derive(Debug)]
+pub struct Interval<C>
+where
+    C: Clock,
+{
+    clock: C,
+    period: Duration,
+    next: Cell<Instant>,
+    missed_tick_behavior: MissedTickBehavior,
+}
+
+impl<C> Interval<C>
+where
+    C: Clock,
+{
+    #[inline]
+    pub fn new(clock: C, period: Duration) -> Self {
+        assert!(!period.is_zero(), "Interval period must be non-zero");
+
+        Self {
+            next: Cell::new(clock.now() + period),
+            clock,
+            period,
+            missed_tick_behavior: MissedTickBehavior::default(),
+        }
+    }
+
+    #[inline]
+    pub const fn period(&self) -> Duration {
+        self.period
+    }
+
+    #[inline]
+    pub const fn missed_tick_behavior(&self) -> MissedTickBehavior {
+        self.missed_tick_behavior
+    }
+
+    #[inline]
+    pub fn set_missed_tick_behavior(&mut self, behavior: MissedTickBehavior) {
+        self.missed_tick_behavior = behavior;
+    }
+
+    /// Sleeps on the clock until the next tick is due and returns it.
+    #[inline]
+    pub fn tick(&mut self) -> Instant {
+        loop {
+            if let Some(tick) = self.poll_tick() {
+                return tick;
+            }
+
+            self.clock.sleep_until(self.next.get());
+        }
+    }
+
+    /// Returns the next due tick without blocking, or `None` if no period
+    /// boundary has been crossed since the last tick.
+    #[inline]
+    pub fn poll_tick(&self) -> Option<Instant> {
+        let now = self.clock.now();
+        let next = self.next.get();
+        if now < next {
+            return None;
+        }
+
+        let tick = match self.missed_tick_behavior {
+            MissedTickBehavior::Burst => next,
+            MissedTickBehavior::Skip => {
+                let behind = (now - next).as_nanos() % self.period.as_nanos();
+                now - Duration::new(
+                    u64::try_from(behind / 1_000_000_000).unwrap_or(u64::MAX),
+                    (behind % 1_000_000_000) as u32,
+                )
+            }
+        };
+        self.next.set(tick + self.period);
+
+        Some(tick)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::testing::ManualClock;
+    use std::sync::Arc;
+
+    #[test]
+    fn bursts_missed_ticks() {
+        let clock = Arc::new(ManualClock::new());
+        let start = clock.now();
+        let interval = Interval::new(Arc::clone(&clock), Duration::from_secs(1));
+
+        assert_eq!(interval.poll_tick(), None);
+
+        clock.advance(Duration::from_secs(3));
+        for secs in 1..=3 {
+            assert_eq!(
+                interval.poll_tick(),
+                Some(start + Duration::from_secs(secs))
+            );
+        }
+        assert_eq!(interval.poll_tick(), None);
+    }
+
+    #[test]
+    fn skips_missed_ticks() {
+        let clock = Arc::new(ManualClock::new());
+        let start = clock.now();
+        let mut interval = Interval::new(Arc::clone(&clock), Duration::from_secs(1));
+        interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+        clock.advance(Duration::from_millis(3500));
+        assert_eq!(interval.poll_tick(), Some(start + Duration::from_secs(3)));
+        assert_eq!(interval.poll_tick(), None);
+
+        clock.advance(Duration::from_millis(500));
+        assert_eq!(interval.tick(), start + Duration::from_secs(4));
+    }
+
+    #[test]
+    fn skips_with_a_period_over_u64_nanos() {
+        let clock = Arc::new(ManualClock::new());
+        let start = clock.now();
+        let period = Duration::from_secs(40_000_000_000);
+        let mut interval = Interval::new(Arc::clone(&clock), period);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+        clock.advance(period * 2 + period / 2);
+        assert_eq!(interval.poll_tick(), Some(start + period * 2));
+    }
+}
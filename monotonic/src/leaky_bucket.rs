@@ -0,0 +1,123 @@
+use crate::{Clock, Instant};
+use std::sync::{Mutex, MutexGuard};
+
+#[derive(Debug)]
+struct Level {
+    level: f64,
+    last_leak: Instant,
+}
+
+/// A leaky-bucket limiter.
+///
+/// Each admitted request adds its `amount` to the bucket, which drains
+/// continuously at `leak_rate` per second; a request is refused if it would
+/// overflow `capacity`. Unlike [`RateLimiter`](crate::RateLimiter), which
+/// starts full and lets a burst of its whole capacity through at once, this
+/// starts empty and smooths the admitted rate toward `leak_rate` once full.
+#[derive(Debug)]
+pub struct LeakyBucket<C>
+where
+    C: Clock,
+{
+    clock: C,
+    leak_rate: f64,
+    capacity: f64,
+    level: Mutex<Level>,
+}
+
+impl<C> LeakyBucket<C>
+where
+    C: Clock,
+{
+    #[inline]
+    pub fn new(clock: C, leak_rate: f64, capacity: f64) -> Self {
+        assert!(
+            leak_rate.is_finite() && leak_rate >= 0.0,
+            "LeakyBucket leak rate must be finite and non-negative, got {leak_rate}"
+        );
+        assert!(
+            capacity.is_finite() && capacity >= 0.0,
+            "LeakyBucket capacity must be finite and non-negative, got {capacity}"
+        );
+
+        Self {
+            level: Mutex::new(Level {
+                level: 0.0,
+                last_leak: clock.now(),
+            }),
+            clock,
+            leak_rate,
+            capacity,
+        }
+    }
+
+    /// Returns the current fill level, after leaking.
+    #[inline]
+    pub fn level(&self) -> f64 {
+        self.leak().level
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `amount` is negative or not finite.
+    #[inline]
+    pub fn try_add(&self, amount: f64) -> bool {
+        assert!(
+            amount.is_finite() && amount >= 0.0,
+            "LeakyBucket amount must be finite and non-negative, got {amount}"
+        );
+
+        let mut level = self.leak();
+
+        if level.level + amount <= self.capacity {
+            level.level += amount;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn leak(&self) -> MutexGuard<'_, Level> {
+        let mut level = self.level.lock().unwrap();
+
+        let now = self.clock.now();
+        let leaked = now.saturating_duration_since(level.last_leak).as_secs_f64() * self.leak_rate;
+        level.level = (level.level - leaked).max(0.0);
+        level.last_leak = now;
+
+        level
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ManualClock;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[test]
+    fn drains_over_time() {
+        let clock = Arc::new(ManualClock::new());
+        let bucket = LeakyBucket::new(Arc::clone(&clock), 2.0, 4.0);
+
+        assert!(bucket.try_add(3.0));
+        assert!(bucket.try_add(1.0));
+        assert!(!bucket.try_add(1.0));
+
+        clock.advance(Duration::from_millis(500));
+        assert_eq!(bucket.level(), 3.0);
+        assert!(bucket.try_add(1.0));
+        assert!(!bucket.try_add(1.0));
+
+        clock.advance(Duration::from_secs(10));
+        assert_eq!(bucket.level(), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "amount must be finite and non-negative")]
+    fn negative_amount_panics() {
+        let bucket = LeakyBucket::new(ManualClock::new(), 1.0, 1.0);
+        bucket.try_add(-1.0);
+    }
+}
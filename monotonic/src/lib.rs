@@ -1,63 +1,562 @@
-use std::ops::{Add, AddAssign, Sub, SubAssign};
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+mod async_clock;
+#[cfg(feature = "std")]
+mod backoff;
+#[cfg(feature = "std")]
+mod bench;
+#[cfg(all(feature = "boot-id", target_os = "linux"))]
+pub mod boot;
+#[cfg(feature = "std")]
+mod circuit_breaker;
+#[cfg(feature = "std")]
+mod coalescing;
+mod countdown;
+#[cfg(feature = "std")]
+mod counting;
+mod deadline;
+#[cfg(feature = "std")]
+mod debounce;
+mod elapsed;
+mod error;
+mod expiring;
+#[cfg(feature = "std")]
+mod frozen;
+#[cfg(feature = "std")]
+pub mod global;
+mod human;
+mod interval;
+#[cfg(feature = "std")]
+mod leaky_bucket;
+#[cfg(feature = "std")]
+mod liveness;
+#[cfg(feature = "std")]
+mod max;
+mod metronome;
+#[cfg(feature = "std")]
+mod observable;
+mod offset;
+mod ordered;
+#[cfg(feature = "std")]
+mod pausable;
+#[cfg(feature = "quanta")]
+mod quanta;
+mod quantized;
+#[cfg(feature = "std")]
+mod rate_limiter;
+mod raw;
+#[cfg(feature = "std")]
+mod scaled;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "std")]
+mod sliding_window;
+mod stopwatch;
+#[cfg(all(test, feature = "std"))]
+mod testing;
+#[cfg(feature = "std")]
+mod throttle;
+#[cfg(not(feature = "std"))]
+mod ticks;
+mod timer;
+#[cfg(feature = "tokio")]
+mod tokio;
+mod total;
+#[cfg(feature = "std")]
+mod ttl_cache;
+#[cfg(all(feature = "std", target_arch = "wasm32", target_os = "unknown"))]
+mod wasm;
+#[cfg(feature = "std")]
+mod watchdog;
+
+pub use async_clock::AsyncClock;
+#[cfg(feature = "std")]
+pub use backoff::Backoff;
+#[cfg(feature = "std")]
+pub use bench::BenchStats;
+#[cfg(feature = "std")]
+pub use circuit_breaker::{CircuitBreaker, CircuitState};
+#[cfg(feature = "std")]
+pub use coalescing::{Coalesce, CoalescingTimer};
+pub use countdown::CountdownTimer;
+#[cfg(feature = "std")]
+pub use counting::CountingClock;
+pub use deadline::Deadline;
+#[cfg(feature = "std")]
+pub use debounce::DebounceGate;
+pub use elapsed::Elapsed;
+pub use error::{ClockDomainMismatch, MismatchPolicy, mismatch_policy, set_mismatch_policy};
+pub use expiring::Expiring;
+#[cfg(feature = "std")]
+pub use frozen::{FrozenClock, UnfreezeMode};
+pub use human::{HumanDuration, HumanDurationExt};
+pub use interval::{Interval, MissedTickBehavior};
+#[cfg(feature = "std")]
+pub use leaky_bucket::LeakyBucket;
+#[cfg(feature = "std")]
+pub use liveness::Liveness;
+#[cfg(feature = "std")]
+pub use max::MaxClock;
+pub use metronome::Metronome;
+#[cfg(feature = "std")]
+pub use observable::ObservableClock;
+pub use offset::OffsetClock;
+pub use ordered::OrderedInstant;
+#[cfg(feature = "std")]
+pub use pausable::PausableClock;
+#[cfg(feature = "quanta")]
+pub use quanta::QuantaClock;
+pub use quantized::QuantizedClock;
+#[cfg(feature = "std")]
+pub use rate_limiter::RateLimiter;
+#[cfg(feature = "std")]
+pub use scaled::ScaledClock;
+#[cfg(feature = "std")]
+pub use sliding_window::SlidingWindowCounter;
+pub use stopwatch::Stopwatch;
+#[cfg(feature = "std")]
+pub use throttle::Throttle;
+#[cfg(not(feature = "std"))]
+pub use ticks::TICK_HZ;
+pub use timer::Timer;
+pub use total::{DurationExt, total_elapsed};
+#[cfg(feature = "std")]
+pub use ttl_cache::TtlCache;
+#[cfg(feature = "std")]
+pub use watchdog::Watchdog;
+
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::rc::Rc;
+#[cfg(feature = "std")]
 use std::sync::Arc;
-use std::time::Duration;
+#[cfg(feature = "std")]
+use std::time::SystemTime;
+
+#[cfg(feature = "std")]
+use raw::RawClock;
+use raw::RawMonotonic;
+
+#[cfg(all(
+    feature = "std",
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+type Inner = std::time::Instant;
+
+#[cfg(all(feature = "std", target_arch = "wasm32", target_os = "unknown"))]
+type Inner = wasm::PerformanceInstant;
+
+#[cfg(not(feature = "std"))]
+type Inner = ticks::Ticks;
 
-#[cfg(debug_assertions)]
+#[cfg(any(debug_assertions, feature = "track-source"))]
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum ClockSource {
     Std,
     Mock(u64),
 }
 
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg(any(debug_assertions, feature = "track-source"))]
+impl ClockSource {
+    /// Returns the mock clock's id, or `None` for the std clock.
+    #[inline]
+    pub const fn id(&self) -> Option<u64> {
+        match self {
+            ClockSource::Std => None,
+            ClockSource::Mock(id) => Some(*id),
+        }
+    }
+}
+
+/// Panics unless all `instants` come from the same clock source.
+///
+/// Does nothing when clock sources are not tracked.
+#[track_caller]
+#[inline]
+pub fn assert_same_domain(instants: &[Instant]) {
+    #[cfg(any(debug_assertions, feature = "track-source"))]
+    if let Some(first) = instants.first()
+        && let Some((i, other)) = instants
+            .iter()
+            .enumerate()
+            .find(|(_, other)| !first.same_source(other))
+    {
+        panic!(
+            "instants are from different clock sources: [0] is {:?} but [{i}] is {:?}",
+            first.source, other.source
+        );
+    }
+
+    #[cfg(not(any(debug_assertions, feature = "track-source")))]
+    let _ = instants;
+}
+
+#[derive(Debug, Clone, Copy)]
 pub struct Instant {
-    inner: std::time::Instant,
-    #[cfg(debug_assertions)]
+    inner: Inner,
+    #[cfg(any(debug_assertions, feature = "track-source"))]
     source: ClockSource,
 }
 
 impl Instant {
+    #[cfg(not(feature = "std"))]
+    #[inline]
+    pub const fn from_ticks(ticks: u64) -> Instant {
+        Instant {
+            inner: ticks::Ticks(ticks),
+            #[cfg(any(debug_assertions, feature = "track-source"))]
+            source: ClockSource::Std,
+        }
+    }
+
+    #[cfg(not(feature = "std"))]
+    #[inline]
+    pub const fn ticks(&self) -> u64 {
+        self.inner.0
+    }
+
+    #[cfg(all(
+        feature = "std",
+        not(all(target_arch = "wasm32", target_os = "unknown"))
+    ))]
+    #[inline]
+    pub const fn as_std(&self) -> std::time::Instant {
+        self.inner
+    }
+
+    /// Wraps a [`std::time::Instant`] as a std clock reading.
+    ///
+    /// Only pass instants that really came from the std clock; anything else
+    /// defeats clock source tracking.
+    #[cfg(all(
+        feature = "std",
+        not(all(target_arch = "wasm32", target_os = "unknown"))
+    ))]
+    #[inline]
+    pub const fn from_std(inner: std::time::Instant) -> Instant {
+        Instant {
+            inner,
+            #[cfg(any(debug_assertions, feature = "track-source"))]
+            source: ClockSource::Std,
+        }
+    }
+
+    #[cfg(any(debug_assertions, feature = "track-source"))]
+    #[inline]
+    pub const fn source(&self) -> ClockSource {
+        self.source
+    }
+
+    /// Returns whether `self` and `other` can be compared or combined.
+    ///
+    /// Always `true` when clock sources are not tracked.
+    #[inline]
+    pub fn same_source(&self, other: &Instant) -> bool {
+        #[cfg(any(debug_assertions, feature = "track-source"))]
+        return self.source == other.source;
+
+        #[cfg(not(any(debug_assertions, feature = "track-source")))]
+        {
+            let _ = other;
+            true
+        }
+    }
+
+    #[inline]
+    fn check_same_source(&self, other: &Instant) -> Result<(), ClockDomainMismatch> {
+        if self.same_source(other) {
+            return Ok(());
+        }
+
+        Err(ClockDomainMismatch {
+            #[cfg(any(debug_assertions, feature = "track-source"))]
+            left: self.source,
+            #[cfg(any(debug_assertions, feature = "track-source"))]
+            right: other.source,
+        })
+    }
+
+    #[inline]
+    fn assert_same_source(&self, other: &Instant) {
+        if let Err(mismatch) = self.check_same_source(other) {
+            mismatch_policy().handle(&mismatch);
+        }
+    }
+
+    /// Like [`duration_since`](Self::duration_since), but returns an error
+    /// instead of panicking if the instants come from different clocks.
+    #[inline]
+    pub fn try_duration_since(&self, earlier: Instant) -> Result<Duration, ClockDomainMismatch> {
+        self.check_same_source(&earlier)?;
+
+        Ok(RawMonotonic::saturating_duration_since(
+            &self.inner,
+            earlier.inner,
+        ))
+    }
+
+    /// Like [`Ord::cmp`], but returns an error instead of panicking if the
+    /// instants come from different clocks.
+    #[inline]
+    pub fn try_cmp(&self, other: &Instant) -> Result<Ordering, ClockDomainMismatch> {
+        self.check_same_source(other)?;
+
+        Ok(self.inner.cmp(&other.inner))
+    }
+
+    /// Returns the time elapsed from `earlier` to `self`.
+    ///
+    /// If `earlier` is actually later, this currently returns zero, but like
+    /// [`std::time::Instant::duration_since`] it may panic in the future.
+    /// When the order isn't guaranteed, such as for instants read on
+    /// different threads, use [`checked_duration_since`](Self::checked_duration_since)
+    /// or [`saturating_duration_since`](Self::saturating_duration_since):
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// # use monotonic::{Clock, StdClock};
+    /// let clock = StdClock;
+    /// let (a, b) = (clock.now(), clock.now());
+    ///
+    /// match b.checked_duration_since(a) {
+    ///     Some(elapsed) => println!("b is {elapsed:?} after a"),
+    ///     None => println!("b precedes a"),
+    /// }
+    /// let gap = a.saturating_duration_since(b);
+    /// # let _ = gap;
+    /// # }
+    /// ```
     #[inline]
     pub fn duration_since(&self, earlier: Instant) -> Duration {
-        #[cfg(debug_assertions)]
-        assert!(self.source == earlier.source);
+        self.assert_same_source(&earlier);
 
-        self.inner.duration_since(earlier.inner)
+        RawMonotonic::saturating_duration_since(&self.inner, earlier.inner)
     }
 
     #[inline]
     pub fn checked_duration_since(&self, earlier: Instant) -> Option<Duration> {
-        #[cfg(debug_assertions)]
-        assert!(self.source == earlier.source);
+        self.assert_same_source(&earlier);
 
-        self.inner.checked_duration_since(earlier.inner)
+        RawMonotonic::checked_duration_since(&self.inner, earlier.inner)
     }
 
     #[inline]
     pub fn saturating_duration_since(&self, earlier: Instant) -> Duration {
-        #[cfg(debug_assertions)]
-        assert!(self.source == earlier.source);
+        self.assert_same_source(&earlier);
+
+        RawMonotonic::saturating_duration_since(&self.inner, earlier.inner)
+    }
+
+    /// Returns the time elapsed from `self` to the clock's current instant,
+    /// with the same caveats as [`duration_since`](Self::duration_since).
+    #[inline]
+    pub fn elapsed<C>(&self, clock: &C) -> Duration
+    where
+        C: Clock + ?Sized,
+    {
+        clock.now().duration_since(*self)
+    }
+
+    /// Returns the time elapsed from `self` to the clock's current instant,
+    /// or `None` if `self` is later.
+    #[inline]
+    pub fn checked_elapsed<C>(&self, clock: &C) -> Option<Duration>
+    where
+        C: Clock + ?Sized,
+    {
+        clock.now().checked_duration_since(*self)
+    }
+
+    /// Returns the time elapsed from `self` to the clock's current instant,
+    /// or zero if `self` is later.
+    #[inline]
+    pub fn saturating_elapsed<C>(&self, clock: &C) -> Duration
+    where
+        C: Clock + ?Sized,
+    {
+        clock.now().saturating_duration_since(*self)
+    }
+
+    /// Formats the offset from `base` to `self` as a [`HumanDuration`], e.g.
+    /// `1.5s`, or `-1.5s` if `self` is earlier.
+    #[inline]
+    pub fn display_relative(&self, base: Instant) -> impl core::fmt::Display {
+        match self.checked_duration_since(base) {
+            Some(offset) => human::RelativeOffset {
+                offset,
+                negative: false,
+            },
+            None => human::RelativeOffset {
+                offset: base.duration_since(*self),
+                negative: true,
+            },
+        }
+    }
 
-        self.inner.saturating_duration_since(earlier.inner)
+    /// Returns the time from `self` until `later`, or `None` if `later` is
+    /// before `self`.
+    #[inline]
+    pub fn checked_duration_until(&self, later: Instant) -> Option<Duration> {
+        later.checked_duration_since(*self)
+    }
+
+    /// Returns the time from the clock's current instant until `self`, or
+    /// `None` if `self` has already passed.
+    #[inline]
+    pub fn checked_duration_until_now<C>(&self, clock: &C) -> Option<Duration>
+    where
+        C: Clock + ?Sized,
+    {
+        clock.now().checked_duration_until(*self)
+    }
+
+    #[inline]
+    pub fn min(self, other: Instant) -> Instant {
+        if self <= other { self } else { other }
+    }
+
+    #[inline]
+    pub fn max(self, other: Instant) -> Instant {
+        if self >= other { self } else { other }
     }
 
     #[inline]
     pub fn checked_add(&self, duration: Duration) -> Option<Instant> {
-        self.inner.checked_add(duration).map(|inner| Self {
+        RawMonotonic::checked_add(&self.inner, duration).map(|inner| Self {
             inner,
-            #[cfg(debug_assertions)]
+            #[cfg(any(debug_assertions, feature = "track-source"))]
             source: self.source,
         })
     }
 
     #[inline]
     pub fn checked_sub(&self, duration: Duration) -> Option<Instant> {
-        self.inner.checked_sub(duration).map(|inner| Self {
+        RawMonotonic::checked_sub(&self.inner, duration).map(|inner| Self {
             inner,
-            #[cfg(debug_assertions)]
+            #[cfg(any(debug_assertions, feature = "track-source"))]
             source: self.source,
         })
     }
+
+    /// Adds `duration`, clamping to the latest representable instant instead
+    /// of overflowing.
+    #[inline]
+    pub fn saturating_add(&self, duration: Duration) -> Instant {
+        self.checked_add(duration)
+            .unwrap_or_else(|| saturate(duration, |d| self.checked_add(d)))
+    }
+
+    /// Subtracts `duration`, clamping to the earliest representable instant
+    /// instead of overflowing.
+    #[inline]
+    pub fn saturating_sub(&self, duration: Duration) -> Instant {
+        self.checked_sub(duration)
+            .unwrap_or_else(|| saturate(duration, |d| self.checked_sub(d)))
+    }
+
+    /// Converts to an approximate wall-clock time using a reference pair of
+    /// readings taken at (nearly) the same moment, typically at startup.
+    ///
+    /// The result is only as accurate as the reference pair, and drifts as
+    /// the wall clock is adjusted or slews relative to the monotonic clock.
+    /// Arithmetic that would overflow [`SystemTime`] saturates.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn to_system_time(&self, ref_instant: Instant, ref_system: SystemTime) -> SystemTime {
+        match self.checked_duration_since(ref_instant) {
+            Some(ahead) => ref_system
+                .checked_add(ahead)
+                .unwrap_or_else(|| saturate(ahead, |d| ref_system.checked_add(d))),
+            None => {
+                let behind = ref_instant - *self;
+                ref_system
+                    .checked_sub(behind)
+                    .unwrap_or_else(|| saturate(behind, |d| ref_system.checked_sub(d)))
+            }
+        }
+    }
+
+    /// The inverse of [`to_system_time`](Instant::to_system_time), with the
+    /// same caveats. The result belongs to the clock domain of `ref_instant`.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn from_system_time(
+        system: SystemTime,
+        ref_instant: Instant,
+        ref_system: SystemTime,
+    ) -> Instant {
+        match system.duration_since(ref_system) {
+            Ok(ahead) => ref_instant.saturating_add(ahead),
+            Err(behind) => ref_instant.saturating_sub(behind.duration()),
+        }
+    }
+}
+
+/// Finds the result of `op` for the largest offset up to `limit` that it
+/// accepts. `op` must accept [`Duration::ZERO`] and reject `limit`.
+#[cold]
+fn saturate<T, F>(limit: Duration, op: F) -> T
+where
+    F: Fn(Duration) -> Option<T>,
+{
+    let from_nanos = |nanos: u128| {
+        let secs = (nanos / 1_000_000_000) as u64;
+        Duration::new(secs, (nanos % 1_000_000_000) as u32)
+    };
+
+    let (mut accepted, mut rejected) = (0, limit.as_nanos());
+    while rejected - accepted > 1 {
+        let mid = accepted + (rejected - accepted) / 2;
+        if op(from_nanos(mid)).is_some() {
+            accepted = mid;
+        } else {
+            rejected = mid;
+        }
+    }
+
+    op(from_nanos(accepted)).expect("zero offset is always representable")
+}
+
+impl PartialEq for Instant {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.assert_same_source(other);
+
+        self.inner == other.inner
+    }
+}
+
+impl Eq for Instant {}
+
+/// Hashes only the underlying reading, never the clock source, so hashes
+/// are the same whether or not clock sources are tracked.
+impl Hash for Instant {
+    #[inline]
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.inner.hash(state);
+    }
+}
+
+impl PartialOrd for Instant {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Instant {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.assert_same_source(other);
+
+        self.inner.cmp(&other.inner)
+    }
 }
 
 impl Add<Duration> for Instant {
@@ -66,8 +565,8 @@ impl Add<Duration> for Instant {
     #[inline]
     fn add(self, rhs: Duration) -> Self::Output {
         Self {
-            inner: self.inner + rhs,
-            #[cfg(debug_assertions)]
+            inner: RawMonotonic::add(&self.inner, rhs),
+            #[cfg(any(debug_assertions, feature = "track-source"))]
             source: self.source,
         }
     }
@@ -86,22 +585,31 @@ impl Sub<Duration> for Instant {
     #[inline]
     fn sub(self, rhs: Duration) -> Self::Output {
         Self {
-            inner: self.inner - rhs,
-            #[cfg(debug_assertions)]
+            inner: RawMonotonic::sub(&self.inner, rhs),
+            #[cfg(any(debug_assertions, feature = "track-source"))]
             source: self.source,
         }
     }
 }
 
+/// Returns the time elapsed from `rhs` to `self`.
+///
+/// If `rhs` is later than `self`, this panics in debug builds to surface the
+/// bug, and returns [`Duration::ZERO`] in release builds so production code
+/// never crashes on it. Use [`Instant::checked_duration_since`] to handle that
+/// case explicitly.
 impl Sub for Instant {
     type Output = Duration;
 
     #[inline]
     fn sub(self, rhs: Self) -> Self::Output {
-        #[cfg(debug_assertions)]
-        assert!(self.source == rhs.source);
+        self.assert_same_source(&rhs);
+        debug_assert!(
+            self.inner >= rhs.inner,
+            "subtracted a later instant from an earlier one"
+        );
 
-        self.inner - rhs.inner
+        RawMonotonic::saturating_duration_since(&self.inner, rhs.inner)
     }
 }
 
@@ -114,79 +622,416 @@ impl SubAssign<Duration> for Instant {
 
 pub trait Clock {
     fn now(&self) -> Instant;
+
+    /// Reads the clock, or returns `None` if the underlying time source is
+    /// unavailable.
+    ///
+    /// The default never fails. Backends that can fail should override this
+    /// and decide separately what [`now`](Clock::now) does on failure.
+    #[inline]
+    fn now_checked(&self) -> Option<Instant> {
+        Some(self.now())
+    }
+
+    /// Returns the wall-clock time corresponding to this clock's current
+    /// reading.
+    ///
+    /// The default reads [`SystemTime::now`]. Mock clocks override this to
+    /// derive it from their own reading instead.
+    #[cfg(feature = "std")]
+    #[inline]
+    fn system_now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    /// Blocks the current thread until this clock reads at least `deadline`.
+    ///
+    /// The default sleeps the thread for the remaining real time, which is
+    /// right for clocks driven by the system clock. Without `std`, or on
+    /// `wasm32-unknown-unknown` where threads can't sleep, it spins on
+    /// [`now`](Clock::now) instead. Clocks driven some other way, such as mocks
+    /// advanced by another thread, should override this.
+    #[inline]
+    fn sleep_until(&self, deadline: Instant) {
+        #[cfg(all(
+            feature = "std",
+            not(all(target_arch = "wasm32", target_os = "unknown"))
+        ))]
+        std::thread::sleep(deadline.saturating_duration_since(self.now()));
+
+        #[cfg(any(
+            not(feature = "std"),
+            all(target_arch = "wasm32", target_os = "unknown")
+        ))]
+        while self.now() < deadline {
+            core::hint::spin_loop();
+        }
+    }
 }
 
 mod private {
     pub trait Sealed {}
-    impl<C> Sealed for C where C: super::Clock {}
+    impl<C> Sealed for C where C: super::Clock + ?Sized {}
 }
 
 pub trait ClockExt: Clock + private::Sealed {
+    /// Returns the time since `instant`, or zero if `instant` is later than
+    /// the clock's current reading.
     fn elapsed_since(&self, instant: Instant) -> Duration;
+
+    /// Like [`elapsed_since`](Self::elapsed_since), in whole milliseconds,
+    /// saturating at `u64::MAX`.
+    fn elapsed_millis_since(&self, instant: Instant) -> u64;
+
+    /// Like [`elapsed_since`](Self::elapsed_since), in whole microseconds,
+    /// saturating at `u64::MAX`.
+    fn elapsed_micros_since(&self, instant: Instant) -> u64;
+
+    /// Returns the time since `instant`, or `None` if `instant` is later than
+    /// the clock's current reading.
+    fn checked_elapsed_since(&self, instant: Instant) -> Option<Duration>;
+
+    /// Reads this clock and the matching [`system_now`](Clock::system_now)
+    /// in immediate succession, for pairing with
+    /// [`Instant::to_system_time`].
+    #[cfg(feature = "std")]
+    fn now_with_system(&self) -> (Instant, SystemTime);
+
+    /// Reads this clock once and returns the reading `n` times, for hot
+    /// loops that need several timestamps close together.
+    ///
+    /// Every timestamp in the batch is the same instant, so the later ones
+    /// are stale by however long the loop takes to reach them. On a mock
+    /// clock nothing moves between them and the batch is exact.
+    #[cfg(feature = "std")]
+    fn now_batch(&self, n: usize) -> Vec<Instant>;
+
+    fn duration_until(&self, instant: Instant) -> Duration;
+
+    fn sleep(&self, duration: Duration);
+
+    /// Runs `f` and returns its result with how long it took on this clock.
+    ///
+    /// On a mock clock the elapsed time is whatever `f` advanced it by.
+    fn measure<T, F>(&self, f: F) -> (T, Duration)
+    where
+        F: FnOnce() -> T;
+
+    /// Like [`measure`](Self::measure), but runs `f` inside a `measure`
+    /// tracing span tagged with `name`, recording the elapsed time as its
+    /// `elapsed` field.
+    #[cfg(feature = "tracing")]
+    fn measure_traced<T, F>(&self, name: &str, f: F) -> (T, Duration)
+    where
+        F: FnOnce() -> T;
+
+    /// Runs `f` `iters` times, timing each run on this clock.
+    ///
+    /// With [`StdClock`] this measures real time. A mock clock only reports
+    /// what `f` advances it by, so results are meaningless unless `f` calls
+    /// `advance`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `iters` is zero.
+    #[cfg(feature = "std")]
+    fn bench<T, F>(&self, iters: usize, f: F) -> BenchStats
+    where
+        F: FnMut() -> T;
+
+    fn deadline_after(&self, duration: Duration) -> Deadline;
+
+    /// Returns the earliest instant at or after the current reading that is a
+    /// whole number of `period`s after `epoch`, or `epoch` itself if it has
+    /// not passed yet.
+    ///
+    /// A zero `period` returns the current reading. Instants past the end of
+    /// the clock's range saturate.
+    fn next_aligned(&self, epoch: Instant, period: Duration) -> Instant;
+
+    /// Runs `f` with a [`Deadline`] `duration` from now.
+    ///
+    /// The timeout is cooperative: nothing interrupts `f`, which must check
+    /// the deadline against this clock at its own checkpoints and give up
+    /// once it has expired. That is also what makes it deterministic under a
+    /// mock clock.
+    fn run_with_timeout<T, F>(&self, duration: Duration, f: F) -> T
+    where
+        F: FnOnce(&Deadline) -> T;
+
+    /// Wraps `instant` as a [`Deadline`], checking that it came from this
+    /// clock when clock sources are tracked.
+    fn deadline_at(&self, instant: Instant) -> Deadline;
 }
 
 impl<C> ClockExt for C
 where
-    C: Clock,
+    C: Clock + ?Sized,
 {
     #[inline]
     fn elapsed_since(&self, instant: Instant) -> Duration {
-        #[cfg(not(debug_assertions))]
-        {
-            self.now() - instant
+        self.now().saturating_duration_since(instant)
+    }
+
+    #[inline]
+    fn elapsed_millis_since(&self, instant: Instant) -> u64 {
+        u64::try_from(self.elapsed_since(instant).as_millis()).unwrap_or(u64::MAX)
+    }
+
+    #[inline]
+    fn elapsed_micros_since(&self, instant: Instant) -> u64 {
+        u64::try_from(self.elapsed_since(instant).as_micros()).unwrap_or(u64::MAX)
+    }
+
+    #[inline]
+    fn checked_elapsed_since(&self, instant: Instant) -> Option<Duration> {
+        self.now().checked_duration_since(instant)
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn now_with_system(&self) -> (Instant, SystemTime) {
+        (self.now(), self.system_now())
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn now_batch(&self, n: usize) -> Vec<Instant> {
+        vec![self.now(); n]
+    }
+
+    #[inline]
+    fn duration_until(&self, instant: Instant) -> Duration {
+        instant.saturating_duration_since(self.now())
+    }
+
+    #[inline]
+    fn sleep(&self, duration: Duration) {
+        self.sleep_until(self.now() + duration);
+    }
+
+    #[inline]
+    fn measure<T, F>(&self, f: F) -> (T, Duration)
+    where
+        F: FnOnce() -> T,
+    {
+        let start = self.now();
+        let result = f();
+        (result, self.elapsed_since(start))
+    }
+
+    #[cfg(feature = "tracing")]
+    #[inline]
+    fn measure_traced<T, F>(&self, name: &str, f: F) -> (T, Duration)
+    where
+        F: FnOnce() -> T,
+    {
+        let span = tracing::info_span!("measure", name, elapsed = tracing::field::Empty);
+        let _entered = span.enter();
+
+        let (result, elapsed) = self.measure(f);
+        span.record("elapsed", tracing::field::debug(elapsed));
+        (result, elapsed)
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn bench<T, F>(&self, iters: usize, mut f: F) -> BenchStats
+    where
+        F: FnMut() -> T,
+    {
+        let mut samples = Vec::with_capacity(iters);
+        for _ in 0..iters {
+            let start = self.now();
+            core::hint::black_box(f());
+            samples.push(self.elapsed_since(start));
         }
 
-        #[cfg(debug_assertions)]
-        {
-            let now = self.now();
-            debug_assert!(now.source == instant.source);
-            now - instant
+        BenchStats::from_samples(&mut samples)
+    }
+
+    #[inline]
+    fn deadline_after(&self, duration: Duration) -> Deadline {
+        Deadline::after(self, duration)
+    }
+
+    #[inline]
+    fn next_aligned(&self, epoch: Instant, period: Duration) -> Instant {
+        let now = self.now();
+        if period.is_zero() {
+            return now;
         }
+
+        let since_epoch = now.saturating_duration_since(epoch).as_nanos();
+        let period = period.as_nanos();
+        let offset = since_epoch.div_ceil(period) * period;
+        let offset = Duration::new(
+            u64::try_from(offset / 1_000_000_000).unwrap_or(u64::MAX),
+            (offset % 1_000_000_000) as u32,
+        );
+        epoch.saturating_add(offset)
+    }
+
+    #[inline]
+    fn run_with_timeout<T, F>(&self, duration: Duration, f: F) -> T
+    where
+        F: FnOnce(&Deadline) -> T,
+    {
+        f(&self.deadline_after(duration))
+    }
+
+    #[inline]
+    fn deadline_at(&self, instant: Instant) -> Deadline {
+        #[cfg(any(debug_assertions, feature = "track-source"))]
+        instant.assert_same_source(&self.now());
+
+        Deadline::at(instant)
     }
 }
 
+#[cfg(feature = "std")]
 impl<T> Clock for Arc<T>
 where
-    T: Clock,
+    T: Clock + ?Sized,
 {
     #[inline]
     fn now(&self) -> Instant {
         (**self).now()
     }
+
+    #[inline]
+    fn now_checked(&self) -> Option<Instant> {
+        (**self).now_checked()
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn system_now(&self) -> SystemTime {
+        (**self).system_now()
+    }
+
+    #[inline]
+    fn sleep_until(&self, deadline: Instant) {
+        (**self).sleep_until(deadline)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Clock for Box<T>
+where
+    T: Clock + ?Sized,
+{
+    #[inline]
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+
+    #[inline]
+    fn now_checked(&self) -> Option<Instant> {
+        (**self).now_checked()
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn system_now(&self) -> SystemTime {
+        (**self).system_now()
+    }
+
+    #[inline]
+    fn sleep_until(&self, deadline: Instant) {
+        (**self).sleep_until(deadline)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Clock for Rc<T>
+where
+    T: Clock + ?Sized,
+{
+    #[inline]
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+
+    #[inline]
+    fn now_checked(&self) -> Option<Instant> {
+        (**self).now_checked()
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn system_now(&self) -> SystemTime {
+        (**self).system_now()
+    }
+
+    #[inline]
+    fn sleep_until(&self, deadline: Instant) {
+        (**self).sleep_until(deadline)
+    }
+}
+
+impl<T> Clock for &T
+where
+    T: Clock + ?Sized,
+{
+    #[inline]
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+
+    #[inline]
+    fn now_checked(&self) -> Option<Instant> {
+        (**self).now_checked()
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn system_now(&self) -> SystemTime {
+        (**self).system_now()
+    }
+
+    #[inline]
+    fn sleep_until(&self, deadline: Instant) {
+        (**self).sleep_until(deadline)
+    }
 }
 
-#[cfg(debug_assertions)]
+#[cfg(all(feature = "std", any(debug_assertions, feature = "track-source")))]
 static NEXT_MOCK_CLOCK_ID: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
 
+#[cfg(feature = "std")]
 pub struct StdClock;
 
-#[cfg(debug_assertions)]
+#[cfg(feature = "std")]
 impl StdClock {
     #[inline]
     pub fn new_mock_epoch() -> Instant {
-        let clock_id = NEXT_MOCK_CLOCK_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
         Instant {
-            inner: std::time::Instant::now(),
-            source: ClockSource::Mock(clock_id),
+            inner: Inner::raw_now(),
+            #[cfg(any(debug_assertions, feature = "track-source"))]
+            source: ClockSource::Mock(
+                NEXT_MOCK_CLOCK_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed),
+            ),
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl Clock for StdClock {
     #[inline]
     fn now(&self) -> Instant {
         Instant {
-            inner: std::time::Instant::now(),
-            #[cfg(debug_assertions)]
+            inner: Inner::raw_now(),
+            #[cfg(any(debug_assertions, feature = "track-source"))]
             source: ClockSource::Std,
         }
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
-    use crate::{Clock, StdClock};
-    use std::time::Duration;
+    use crate::{Clock, ClockExt, Instant, StdClock};
+    use std::time::{Duration, SystemTime};
 
     #[test]
     fn test() {
@@ -197,4 +1042,449 @@ mod tests {
 
         assert!(actual_start - start.inner < Duration::from_millis(1));
     }
+
+    #[test]
+    fn now_checked_forwards_through_pointers() {
+        struct Unavailable;
+
+        impl Clock for Unavailable {
+            fn now(&self) -> Instant {
+                panic!("time source unavailable")
+            }
+
+            fn now_checked(&self) -> Option<Instant> {
+                None
+            }
+        }
+
+        assert!(StdClock.now_checked().is_some());
+        assert!(std::sync::Arc::new(Unavailable).now_checked().is_none());
+        assert!((&Unavailable as &dyn Clock).now_checked().is_none());
+    }
+
+    #[test]
+    fn instant_elapsed() {
+        let clock = crate::testing::ManualClock::new();
+        let start = clock.now();
+
+        clock.advance(Duration::from_secs(3));
+
+        assert_eq!(start.elapsed(&clock), Duration::from_secs(3));
+        assert_eq!(start.checked_elapsed(&clock), Some(Duration::from_secs(3)));
+        assert_eq!(start.saturating_elapsed(&clock), Duration::from_secs(3));
+
+        let future = clock.now() + Duration::from_secs(1);
+        assert_eq!(future.checked_elapsed(&clock), None);
+        assert_eq!(future.saturating_elapsed(&clock), Duration::ZERO);
+    }
+
+    #[test]
+    fn display_relative_to_base() {
+        let base = StdClock.now();
+
+        let later = base + Duration::from_millis(1500);
+        assert_eq!(later.display_relative(base).to_string(), "1.5s");
+        assert_eq!(base.display_relative(later).to_string(), "-1.5s");
+        assert_eq!(base.display_relative(base).to_string(), "0ns");
+    }
+
+    #[test]
+    fn rc_clock() {
+        let clock = std::rc::Rc::new(crate::testing::ManualClock::new());
+        let shared = std::rc::Rc::clone(&clock);
+        let start = shared.now();
+
+        clock.advance(Duration::from_secs(1));
+
+        assert_eq!(shared.elapsed_since(start), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn duration_until_saturates() {
+        let clock = crate::testing::ManualClock::new();
+        let target = clock.now() + Duration::from_secs(2);
+
+        assert_eq!(clock.duration_until(target), Duration::from_secs(2));
+        clock.advance(Duration::from_secs(3));
+        assert_eq!(clock.duration_until(target), Duration::ZERO);
+    }
+
+    #[cfg(any(debug_assertions, feature = "track-source"))]
+    #[test]
+    #[should_panic]
+    fn instant_elapsed_other_clock_panics() {
+        let start = crate::testing::ManualClock::new().now();
+
+        start.elapsed(&StdClock);
+    }
+
+    #[test]
+    fn saturating_arithmetic() {
+        let now = StdClock.now();
+
+        let latest = now.saturating_add(Duration::MAX);
+        assert!(latest > now);
+        assert_eq!(latest.checked_add(Duration::from_nanos(1)), None);
+        assert_eq!(
+            now.saturating_add(Duration::from_secs(1)),
+            now + Duration::from_secs(1)
+        );
+
+        let earliest = now.saturating_sub(Duration::MAX);
+        assert!(earliest <= now);
+        assert_eq!(earliest.checked_sub(Duration::from_nanos(1)), None);
+        assert_eq!(
+            now.saturating_sub(Duration::from_secs(1)),
+            now - Duration::from_secs(1)
+        );
+    }
+
+    #[test]
+    fn min_max() {
+        let earlier = StdClock.now();
+        let later = earlier + Duration::from_secs(1);
+
+        assert_eq!(earlier.min(later), earlier);
+        assert_eq!(later.min(earlier), earlier);
+        assert_eq!(earlier.max(later), later);
+        assert_eq!(later.max(earlier), later);
+    }
+
+    #[test]
+    fn checked_duration_until() {
+        let clock = crate::testing::ManualClock::new();
+        let target = clock.now() + Duration::from_secs(2);
+
+        assert_eq!(
+            clock.now().checked_duration_until(target),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(target.checked_duration_until(clock.now()), None);
+        assert_eq!(
+            target.checked_duration_until_now(&clock),
+            Some(Duration::from_secs(2))
+        );
+
+        clock.advance(Duration::from_secs(3));
+        assert_eq!(target.checked_duration_until_now(&clock), None);
+    }
+
+    #[test]
+    fn elapsed_since_future_instant_is_zero() {
+        let clock = crate::testing::ManualClock::new();
+
+        assert_eq!(
+            clock.elapsed_since(clock.now() + Duration::from_secs(1)),
+            Duration::ZERO
+        );
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn measure_traced_records_elapsed() {
+        use std::sync::Mutex;
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+
+        #[derive(Default)]
+        struct Fields(Mutex<Vec<String>>);
+
+        impl Visit for &Fields {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                self.0.lock().unwrap().push(format!("{field}={value:?}"));
+            }
+        }
+
+        impl tracing::Subscriber for Fields {
+            fn enabled(&self, _: &tracing::Metadata<'_>) -> bool {
+                true
+            }
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                span.record(&mut &*self);
+                Id::from_u64(1)
+            }
+            fn record(&self, _: &Id, values: &Record<'_>) {
+                values.record(&mut &*self);
+            }
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+            fn event(&self, _: &tracing::Event<'_>) {}
+            fn enter(&self, _: &Id) {}
+            fn exit(&self, _: &Id) {}
+        }
+
+        let fields = std::sync::Arc::new(Fields::default());
+        let clock = crate::testing::ManualClock::new();
+        let (result, elapsed) =
+            tracing::subscriber::with_default(std::sync::Arc::clone(&fields), || {
+                clock.measure_traced("work", || clock.advance(Duration::from_millis(5)))
+            });
+
+        assert_eq!(result, ());
+        assert_eq!(elapsed, Duration::from_millis(5));
+        assert_eq!(*fields.0.lock().unwrap(), ["name=\"work\"", "elapsed=5ms"]);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "later instant from an earlier one")]
+    fn sub_later_instant_panics_in_debug() {
+        let earlier = StdClock.now();
+
+        let _ = earlier - (earlier + Duration::from_secs(1));
+    }
+
+    #[cfg(not(debug_assertions))]
+    #[test]
+    fn sub_later_instant_saturates_in_release() {
+        let earlier = StdClock.now();
+
+        assert_eq!(earlier - (earlier + Duration::from_secs(1)), Duration::ZERO);
+    }
+
+    #[test]
+    fn elapsed_integer_units() {
+        let clock = crate::testing::ManualClock::new();
+        let start = clock.now();
+
+        clock.advance(Duration::from_micros(2_500_700));
+        assert_eq!(clock.elapsed_millis_since(start), 2_500);
+        assert_eq!(clock.elapsed_micros_since(start), 2_500_700);
+        assert_eq!(
+            clock.elapsed_millis_since(clock.now() + Duration::from_secs(1)),
+            0
+        );
+    }
+
+    #[test]
+    fn checked_elapsed_since() {
+        let clock = crate::testing::ManualClock::new();
+        let start = clock.now();
+        let later = start + Duration::from_secs(1);
+
+        assert_eq!(clock.checked_elapsed_since(later), None);
+        clock.advance(Duration::from_secs(3));
+        assert_eq!(
+            clock.checked_elapsed_since(later),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(
+            clock.checked_elapsed_since(start),
+            Some(Duration::from_secs(3))
+        );
+    }
+
+    #[test]
+    fn hash_ignores_source() {
+        use std::hash::BuildHasher;
+
+        let std = StdClock.now();
+        let hasher = std::collections::hash_map::RandomState::new();
+
+        assert_eq!(hasher.hash_one(std), hasher.hash_one(std.inner));
+    }
+
+    #[test]
+    fn measure() {
+        let clock = crate::testing::ManualClock::new();
+
+        let (result, elapsed) = clock.measure(|| {
+            clock.advance(Duration::from_secs(2));
+            "done"
+        });
+        assert_eq!(result, "done");
+        assert_eq!(elapsed, Duration::from_secs(2));
+    }
+
+    #[test]
+    fn now_batch_repeats_one_reading() {
+        let clock = crate::testing::ManualClock::new();
+        let now = clock.now();
+
+        assert_eq!(clock.now_batch(3), [now; 3]);
+        assert!(clock.now_batch(0).is_empty());
+
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(clock.now_batch(2), [now + Duration::from_secs(1); 2]);
+    }
+
+    #[test]
+    fn next_aligned_rounds_up_to_the_grid() {
+        let clock = crate::testing::ManualClock::new();
+        let epoch = clock.now();
+        let period = Duration::from_secs(1);
+
+        assert_eq!(clock.next_aligned(epoch, period), epoch);
+        clock.advance(Duration::from_millis(2300));
+        assert_eq!(
+            clock.next_aligned(epoch, period),
+            epoch + Duration::from_secs(3)
+        );
+        clock.advance(Duration::from_millis(700));
+        assert_eq!(
+            clock.next_aligned(epoch, period),
+            epoch + Duration::from_secs(3)
+        );
+
+        let future = clock.now() + Duration::from_secs(10);
+        assert_eq!(clock.next_aligned(future, period), future);
+        assert_eq!(clock.next_aligned(epoch, Duration::ZERO), clock.now());
+    }
+
+    #[test]
+    fn run_with_timeout_stops_at_checkpoint() {
+        let clock = crate::testing::ManualClock::new();
+
+        let steps = clock.run_with_timeout(Duration::from_secs(3), |deadline| {
+            let mut steps = 0;
+            while !deadline.is_expired(&clock) {
+                clock.advance(Duration::from_secs(1));
+                steps += 1;
+            }
+            steps
+        });
+        assert_eq!(steps, 3);
+    }
+
+    #[test]
+    fn deadlines_from_clock() {
+        let clock = crate::testing::ManualClock::new();
+        let at = clock.now() + Duration::from_secs(3);
+
+        let after = clock.deadline_after(Duration::from_secs(3));
+        assert_eq!(after, clock.deadline_at(at));
+        assert_eq!(after.remaining(&clock), Duration::from_secs(3));
+    }
+
+    #[test]
+    fn std_round_trip() {
+        let std = std::time::Instant::now();
+        let instant = Instant::from_std(std);
+
+        assert_eq!(instant.as_std(), std);
+        assert!(instant.same_source(&StdClock.now()));
+    }
+
+    #[test]
+    fn same_source() {
+        let std = StdClock.now();
+        let mock = crate::testing::ManualClock::new().now();
+
+        assert!(std.same_source(&(std + Duration::from_secs(1))));
+        #[cfg(any(debug_assertions, feature = "track-source"))]
+        {
+            assert!(!std.same_source(&mock));
+            assert_eq!(std.source().id(), None);
+            assert!(mock.source().id().is_some());
+        }
+        #[cfg(not(any(debug_assertions, feature = "track-source")))]
+        assert!(std.same_source(&mock));
+    }
+
+    #[cfg(any(debug_assertions, feature = "track-source"))]
+    #[test]
+    #[should_panic(expected = "[0] is Std but [2] is Mock")]
+    fn assert_same_domain_names_the_odd_one_out() {
+        let std = StdClock.now();
+        crate::assert_same_domain(&[]);
+        crate::assert_same_domain(&[std, std + Duration::from_secs(1)]);
+
+        crate::assert_same_domain(&[std, std, crate::testing::ManualClock::new().now()]);
+    }
+
+    #[cfg(any(debug_assertions, feature = "track-source"))]
+    #[test]
+    fn const_clock_source() {
+        const SOURCE: crate::ClockSource = crate::ClockSource::Mock(7);
+        const ID: Option<u64> = SOURCE.id();
+
+        assert_eq!(ID, Some(7));
+        assert_eq!(const { crate::ClockSource::Std.id() }, None);
+    }
+
+    #[test]
+    fn try_across_sources() {
+        let std = StdClock.now();
+        let later = std + Duration::from_secs(1);
+        assert_eq!(later.try_duration_since(std), Ok(Duration::from_secs(1)));
+        assert_eq!(std.try_cmp(&later), Ok(std::cmp::Ordering::Less));
+
+        #[cfg(any(debug_assertions, feature = "track-source"))]
+        {
+            let mock = crate::testing::ManualClock::new().now();
+            let mismatch = std.try_duration_since(mock).unwrap_err();
+            assert_eq!(mismatch.sources(), (std.source(), mock.source()));
+            assert!(mismatch.to_string().contains("different clock sources"));
+            assert_eq!(mock.try_cmp(&std).unwrap_err().sources().0, mock.source());
+        }
+    }
+
+    #[cfg(any(debug_assertions, feature = "track-source"))]
+    #[test]
+    #[should_panic(expected = "different clock sources")]
+    fn equality_across_sources_panics() {
+        let mock = crate::testing::ManualClock::new().now();
+
+        let _ = mock == StdClock.now();
+    }
+
+    #[cfg(any(debug_assertions, feature = "track-source"))]
+    #[test]
+    #[should_panic(expected = "different clock sources")]
+    fn max_across_sources_panics() {
+        let mock = crate::testing::ManualClock::new().now();
+
+        mock.max(StdClock.now());
+    }
+
+    #[cfg(any(debug_assertions, feature = "track-source"))]
+    #[test]
+    #[should_panic(expected = "different clock sources")]
+    fn comparing_across_sources_panics() {
+        let mock = crate::testing::ManualClock::new().now();
+
+        let _ = mock < StdClock.now();
+    }
+
+    #[test]
+    fn system_time_round_trip() {
+        let (ref_instant, ref_system) = (StdClock.now(), SystemTime::now());
+
+        let later = ref_instant + Duration::from_secs(90);
+        let earlier = ref_instant - Duration::from_secs(30);
+
+        assert_eq!(
+            later.to_system_time(ref_instant, ref_system),
+            ref_system + Duration::from_secs(90)
+        );
+        assert_eq!(
+            earlier.to_system_time(ref_instant, ref_system),
+            ref_system - Duration::from_secs(30)
+        );
+        assert_eq!(
+            Instant::from_system_time(
+                later.to_system_time(ref_instant, ref_system),
+                ref_instant,
+                ref_system
+            ),
+            later
+        );
+        assert_eq!(
+            Instant::from_system_time(
+                earlier.to_system_time(ref_instant, ref_system),
+                ref_instant,
+                ref_system
+            ),
+            earlier
+        );
+    }
+
+    #[test]
+    fn std_clock_sleep() {
+        let clock = StdClock;
+
+        let start = clock.now();
+        clock.sleep(Duration::from_millis(5));
+
+        assert!(clock.elapsed_since(start) >= Duration::from_millis(5));
+    }
 }
@@ -0,0 +1,91 @@
+use crate::{Clock, Instant};
+use core::borrow::Borrow;
+use core::hash::Hash;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Tracks heartbeats from a set of components.
+///
+/// A component is alive if it has sent a heartbeat within the last
+/// `timeout`; one that has never sent a heartbeat is not.
+#[derive(Debug)]
+pub struct Liveness<K, C>
+where
+    C: Clock,
+{
+    clock: C,
+    last_seen: Mutex<HashMap<K, Instant>>,
+}
+
+impl<K, C> Liveness<K, C>
+where
+    K: Eq + Hash + Clone,
+    C: Clock,
+{
+    #[inline]
+    pub fn new(clock: C) -> Self {
+        Self {
+            clock,
+            last_seen: Mutex::new(HashMap::new()),
+        }
+    }
+
+    #[inline]
+    pub fn heartbeat(&self, id: K) {
+        let now = self.clock.now();
+        self.last_seen.lock().unwrap().insert(id, now);
+    }
+
+    #[inline]
+    pub fn is_alive<Q>(&self, id: &Q, timeout: Duration) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let now = self.clock.now();
+        self.last_seen
+            .lock()
+            .unwrap()
+            .get(id)
+            .is_some_and(|last_seen| now.saturating_duration_since(*last_seen) < timeout)
+    }
+
+    #[inline]
+    pub fn stale_ids(&self, timeout: Duration) -> Vec<K> {
+        let now = self.clock.now();
+        self.last_seen
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, last_seen)| now.saturating_duration_since(**last_seen) >= timeout)
+            .map(|(id, _)| id.clone())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ManualClock;
+    use std::sync::Arc;
+
+    #[test]
+    fn goes_stale_after_timeout() {
+        let clock = Arc::new(ManualClock::new());
+        let liveness = Liveness::new(Arc::clone(&clock));
+        let timeout = Duration::from_secs(5);
+        assert!(!liveness.is_alive("db", timeout));
+
+        liveness.heartbeat("db");
+        liveness.heartbeat("cache");
+        clock.advance(Duration::from_secs(3));
+        liveness.heartbeat("cache");
+        assert!(liveness.stale_ids(timeout).is_empty());
+
+        clock.advance(Duration::from_secs(2));
+        assert!(!liveness.is_alive("db", timeout));
+        assert!(liveness.is_alive("cache", timeout));
+        assert_eq!(liveness.stale_ids(timeout), ["db"]);
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{Clock, Instant};
+
+/// Reads the latest of several clocks, all of which must share a clock
+/// source.
+pub struct MaxClock {
+    clocks: Vec<Box<dyn Clock>>,
+}
+
+impl MaxClock {
+    #[inline]
+    pub fn new(clocks: Vec<Box<dyn Clock>>) -> Self {
+        assert!(!clocks.is_empty(), "MaxClock needs at least one clock");
+
+        Self { clocks }
+    }
+
+    #[inline]
+    fn leader(&self) -> (&dyn Clock, Instant) {
+        self.clocks
+            .iter()
+            .map(|clock| (&**clock, clock.now()))
+            .max_by(|(_, a), (_, b)| a.cmp(b))
+            .expect("MaxClock is never empty")
+    }
+}
+
+impl Clock for MaxClock {
+    #[inline]
+    fn now(&self) -> Instant {
+        self.leader().1
+    }
+
+    /// Sleeps on whichever clock currently reads the latest.
+    #[inline]
+    fn sleep_until(&self, deadline: Instant) {
+        let (leader, now) = self.leader();
+        if now < deadline {
+            leader.sleep_until(deadline);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OffsetClock;
+    use crate::testing::ManualClock;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[test]
+    fn reads_the_latest_clock() {
+        let shared = Arc::new(ManualClock::new());
+        let start = shared.now();
+
+        let clock = MaxClock::new(vec![
+            Box::new(OffsetClock::behind(
+                Arc::clone(&shared),
+                Duration::from_secs(1),
+            )),
+            Box::new(Arc::clone(&shared)),
+            Box::new(OffsetClock::ahead(
+                Arc::clone(&shared),
+                Duration::from_secs(2),
+            )),
+        ]);
+
+        assert_eq!(clock.now() - start, Duration::from_secs(2));
+
+        shared.advance(Duration::from_secs(1));
+        assert_eq!(clock.now() - start, Duration::from_secs(3));
+    }
+
+    #[cfg(any(debug_assertions, feature = "track-source"))]
+    #[test]
+    #[should_panic]
+    fn mixing_sources_panics() {
+        let clock = MaxClock::new(vec![
+            Box::new(ManualClock::new()),
+            Box::new(crate::StdClock),
+        ]);
+
+        clock.now();
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{Clock, Instant};
+use core::time::Duration;
+
+/// Yields deadlines on a fixed grid of `period` steps from an anchor.
+///
+/// Each deadline is exactly one period after the previous one, however late
+/// it is consumed, so lateness never shifts the phase. Unlike
+/// [`Interval`](crate::Interval), nothing is skipped: late callers get every
+/// grid point in turn.
+#[derive(Debug)]
+pub struct Metronome<C>
+where
+    C: Clock,
+{
+    clock: C,
+    period: Duration,
+    anchor: Instant,
+    next: Instant,
+}
+
+impl<C> Metronome<C>
+where
+    C: Clock,
+{
+    #[inline]
+    pub fn new(clock: C, period: Duration) -> Self {
+        assert!(!period.is_zero(), "Metronome period must be non-zero");
+
+        let anchor = clock.now();
+        Self {
+            clock,
+            period,
+            anchor,
+            next: anchor,
+        }
+    }
+
+    #[inline]
+    pub const fn period(&self) -> Duration {
+        self.period
+    }
+
+    #[inline]
+    pub const fn anchor(&self) -> Instant {
+        self.anchor
+    }
+
+    /// Returns the next grid point, starting one period after the anchor.
+    #[inline]
+    pub fn next_deadline(&mut self) -> Instant {
+        self.next += self.period;
+        self.next
+    }
+
+    /// Sleeps on the clock until the next grid point and returns it.
+    #[inline]
+    pub fn tick(&mut self) -> Instant {
+        let deadline = self.next_deadline();
+        self.clock.sleep_until(deadline);
+        deadline
+    }
+
+    /// Re-anchors the grid at the current instant.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.anchor = self.clock.now();
+        self.next = self.anchor;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::testing::ManualClock;
+    use std::sync::Arc;
+
+    #[test]
+    fn keeps_phase_through_irregular_advances() {
+        let clock = Arc::new(ManualClock::new());
+        let mut metronome = Metronome::new(Arc::clone(&clock), Duration::from_millis(10));
+        let anchor = metronome.anchor();
+
+        for (n, advance) in [3, 17, 1, 40, 9].into_iter().enumerate() {
+            clock.advance(Duration::from_millis(advance));
+            let deadline = metronome.next_deadline();
+            assert_eq!(
+                deadline - anchor,
+                Duration::from_millis(10 * (n as u64 + 1))
+            );
+        }
+
+        clock.advance(Duration::from_millis(5));
+        metronome.reset();
+        assert_eq!(metronome.anchor() - anchor, Duration::from_millis(75));
+        assert_eq!(metronome.tick(), clock.now());
+        assert_eq!(metronome.anchor() + Duration::from_millis(10), clock.now());
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{Clock, Instant};
+use std::sync::Mutex;
+
+/// Records every reading of `inner` along with a caller-supplied tag.
+///
+/// Plain [`now`](Clock::now) reads are recorded with an empty tag.
+#[derive(Debug)]
+pub struct ObservableClock<C>
+where
+    C: Clock,
+{
+    inner: C,
+    log: Mutex<Vec<(Instant, &'static str)>>,
+}
+
+impl<C> ObservableClock<C>
+where
+    C: Clock,
+{
+    #[inline]
+    pub fn new(inner: C) -> Self {
+        Self {
+            inner,
+            log: Mutex::new(Vec::new()),
+        }
+    }
+
+    #[inline]
+    pub fn now_tagged(&self, tag: &'static str) -> Instant {
+        let now = self.inner.now();
+        self.log.lock().unwrap().push((now, tag));
+        now
+    }
+
+    /// Takes the readings recorded so far, oldest first.
+    #[inline]
+    pub fn drain_log(&self) -> Vec<(Instant, &'static str)> {
+        std::mem::take(&mut *self.log.lock().unwrap())
+    }
+}
+
+impl<C> Clock for ObservableClock<C>
+where
+    C: Clock,
+{
+    #[inline]
+    fn now(&self) -> Instant {
+        self.now_tagged("")
+    }
+
+    #[inline]
+    fn sleep_until(&self, deadline: Instant) {
+        self.inner.sleep_until(deadline);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ManualClock;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[test]
+    fn logs_reads_in_order() {
+        let inner = Arc::new(ManualClock::new());
+        let clock = ObservableClock::new(Arc::clone(&inner));
+
+        let first = clock.now_tagged("start");
+        inner.advance(Duration::from_secs(1));
+        let second = clock.now();
+
+        assert_eq!(clock.drain_log(), [(first, "start"), (second, "")]);
+        assert!(clock.drain_log().is_empty());
+    }
+}
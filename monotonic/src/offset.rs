@@ -0,0 +1,119 @@
+use crate::{Clock, Instant};
+use core::time::Duration;
+
+#[derive(Debug, Clone, Copy)]
+enum Skew {
+    Ahead(Duration),
+    Behind { offset: Duration, epoch: Instant },
+}
+
+#[derive(Debug)]
+pub struct OffsetClock<C>
+where
+    C: Clock,
+{
+    clock: C,
+    skew: Skew,
+}
+
+impl<C> OffsetClock<C>
+where
+    C: Clock,
+{
+    #[inline]
+    pub fn new(clock: C, offset: Duration) -> Self {
+        Self::ahead(clock, offset)
+    }
+
+    #[inline]
+    pub fn ahead(clock: C, offset: Duration) -> Self {
+        Self {
+            clock,
+            skew: Skew::Ahead(offset),
+        }
+    }
+
+    /// Creates a clock that lags `clock` by `offset`.
+    ///
+    /// Until `offset` has elapsed on `clock`, readings saturate at the instant
+    /// the wrapper was created rather than reaching back before it.
+    #[inline]
+    pub fn behind(clock: C, offset: Duration) -> Self {
+        Self {
+            skew: Skew::Behind {
+                offset,
+                epoch: clock.now(),
+            },
+            clock,
+        }
+    }
+}
+
+impl<C> Clock for OffsetClock<C>
+where
+    C: Clock,
+{
+    #[inline]
+    fn now(&self) -> Instant {
+        let now = self.clock.now();
+        match self.skew {
+            Skew::Ahead(offset) => now.saturating_add(offset),
+            Skew::Behind { offset, epoch } => now
+                .checked_sub(offset)
+                .filter(|skewed| *skewed >= epoch)
+                .unwrap_or(epoch),
+        }
+    }
+
+    #[inline]
+    fn sleep_until(&self, deadline: Instant) {
+        if self.now() >= deadline {
+            return;
+        }
+
+        match self.skew {
+            Skew::Ahead(offset) => self.clock.sleep_until(deadline - offset),
+            Skew::Behind { offset, .. } => self.clock.sleep_until(deadline.saturating_add(offset)),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::testing::ManualClock;
+    use std::sync::Arc;
+
+    #[test]
+    fn skews_a_shared_clock() {
+        let shared = Arc::new(ManualClock::new());
+        let start = shared.now();
+
+        let ahead = OffsetClock::ahead(Arc::clone(&shared), Duration::from_secs(2));
+        let behind = OffsetClock::behind(Arc::clone(&shared), Duration::from_secs(2));
+
+        assert_eq!(ahead.now() - start, Duration::from_secs(2));
+        assert_eq!(behind.now(), start);
+
+        shared.advance(Duration::from_secs(1));
+        assert_eq!(ahead.now() - start, Duration::from_secs(3));
+        assert_eq!(behind.now(), start);
+
+        shared.advance(Duration::from_secs(4));
+        assert_eq!(ahead.now() - start, Duration::from_secs(7));
+        assert_eq!(behind.now() - start, Duration::from_secs(3));
+    }
+
+    #[test]
+    fn huge_offset_saturates() {
+        let clock = ManualClock::new();
+        let start = clock.now();
+        let ahead = OffsetClock::ahead(&clock, Duration::MAX);
+
+        let now = ahead.now();
+        assert!(now > start);
+
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(ahead.now(), now);
+    }
+}
@@ -0,0 +1,68 @@
+use crate::Instant;
+
+/// An [`Instant`] meant to be used as a key in ordered collections such as a
+/// `BTreeMap` of pending timers.
+///
+/// All keys in one collection must come from the same clock. When clock
+/// sources are tracked, comparing keys from different clocks panics, so
+/// inserting a mismatched key fails loudly instead of corrupting the
+/// collection's order. Within one clock the order is total.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct OrderedInstant(Instant);
+
+impl OrderedInstant {
+    #[inline]
+    pub const fn new(instant: Instant) -> Self {
+        Self(instant)
+    }
+
+    #[inline]
+    pub const fn into_inner(self) -> Instant {
+        self.0
+    }
+}
+
+impl From<Instant> for OrderedInstant {
+    #[inline]
+    fn from(instant: Instant) -> Self {
+        Self(instant)
+    }
+}
+
+impl From<OrderedInstant> for Instant {
+    #[inline]
+    fn from(instant: OrderedInstant) -> Self {
+        instant.0
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{Clock, StdClock};
+    use std::collections::BTreeMap;
+    use std::time::Duration;
+
+    #[test]
+    fn orders_map_keys() {
+        let now = StdClock.now();
+        let mut timers = BTreeMap::new();
+        timers.insert(OrderedInstant::new(now + Duration::from_secs(2)), "late");
+        timers.insert(OrderedInstant::new(now + Duration::from_secs(1)), "early");
+
+        let (first, _) = timers.pop_first().unwrap();
+        assert_eq!(first.into_inner(), now + Duration::from_secs(1));
+    }
+
+    #[cfg(any(debug_assertions, feature = "track-source"))]
+    #[test]
+    #[should_panic(expected = "different clock sources")]
+    fn mismatched_key_panics() {
+        use crate::testing::ManualClock;
+
+        let mut timers = BTreeMap::new();
+        timers.insert(OrderedInstant::from(StdClock.now()), ());
+
+        timers.insert(OrderedInstant::from(ManualClock::new().now()), ());
+    }
+}
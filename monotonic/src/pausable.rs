@@ -0,0 +1,122 @@
+use crate::{Clock, ClockExt, Instant};
+use std::sync::Mutex;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy)]
+struct State {
+    frozen: Duration,
+    paused_at: Option<Instant>,
+}
+
+#[derive(Debug)]
+pub struct PausableClock<C>
+where
+    C: Clock,
+{
+    clock: C,
+    base: Instant,
+    state: Mutex<State>,
+}
+
+impl<C> PausableClock<C>
+where
+    C: Clock,
+{
+    #[inline]
+    pub fn new(clock: C) -> Self {
+        Self {
+            base: clock.now(),
+            clock,
+            state: Mutex::new(State {
+                frozen: Duration::ZERO,
+                paused_at: None,
+            }),
+        }
+    }
+
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        self.state.lock().unwrap().paused_at.is_some()
+    }
+
+    #[inline]
+    pub fn pause(&self) {
+        let mut state = self.state.lock().unwrap();
+        if state.paused_at.is_none() {
+            state.paused_at = Some(self.clock.now());
+        }
+    }
+
+    #[inline]
+    pub fn resume(&self) {
+        let mut state = self.state.lock().unwrap();
+        if let Some(paused_at) = state.paused_at.take() {
+            state.frozen += self.clock.elapsed_since(paused_at);
+        }
+    }
+
+    #[inline]
+    fn read(&self, state: &State) -> Instant {
+        let inner_now = state.paused_at.unwrap_or_else(|| self.clock.now());
+        self.base
+            + inner_now
+                .saturating_duration_since(self.base)
+                .saturating_sub(state.frozen)
+    }
+}
+
+impl<C> Clock for PausableClock<C>
+where
+    C: Clock,
+{
+    #[inline]
+    fn now(&self) -> Instant {
+        self.read(&self.state.lock().unwrap())
+    }
+
+    #[inline]
+    fn sleep_until(&self, deadline: Instant) {
+        loop {
+            let now = self.now();
+            if now >= deadline {
+                return;
+            }
+
+            self.clock.sleep(deadline - now);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ManualClock;
+    use std::sync::Arc;
+
+    #[test]
+    fn paused_time_is_not_counted() {
+        let inner = Arc::new(ManualClock::new());
+        let clock = PausableClock::new(Arc::clone(&inner));
+        let start = clock.now();
+
+        inner.advance(Duration::from_secs(1));
+        clock.pause();
+        assert!(clock.is_paused());
+        let paused = clock.now();
+
+        inner.advance(Duration::from_secs(5));
+        assert_eq!(clock.now(), paused);
+
+        clock.resume();
+        assert!(!clock.is_paused());
+        assert_eq!(clock.now(), paused);
+
+        inner.advance(Duration::from_secs(2));
+        assert_eq!(clock.now() - start, Duration::from_secs(3));
+
+        clock.pause();
+        inner.advance(Duration::from_secs(1));
+        clock.resume();
+        assert_eq!(clock.now() - start, Duration::from_secs(3));
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{Clock, Instant};
+use core::time::Duration;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// Rounds readings of `inner` down to a grid of `resolution` steps.
+///
+/// The grid starts at `inner`'s reading when the clock is created. Flooring
+/// preserves `inner`'s ordering, so readings never go backward.
+#[derive(Debug)]
+pub struct QuantizedClock<C>
+where
+    C: Clock,
+{
+    inner: C,
+    base: Instant,
+    resolution: Duration,
+}
+
+impl<C> QuantizedClock<C>
+where
+    C: Clock,
+{
+    #[inline]
+    pub fn new(inner: C, resolution: Duration) -> Self {
+        assert!(
+            !resolution.is_zero(),
+            "QuantizedClock resolution must be non-zero"
+        );
+
+        Self {
+            base: inner.now(),
+            inner,
+            resolution,
+        }
+    }
+
+    #[inline]
+    pub const fn resolution(&self) -> Duration {
+        self.resolution
+    }
+
+    #[inline]
+    fn steps(&self, offset: Duration) -> u128 {
+        offset.as_nanos() / self.resolution.as_nanos()
+    }
+
+    #[inline]
+    fn grid_point(&self, steps: u128) -> Instant {
+        let nanos = steps * self.resolution.as_nanos();
+        let offset = Duration::new(
+            (nanos / NANOS_PER_SEC) as u64,
+            (nanos % NANOS_PER_SEC) as u32,
+        );
+        self.base + offset
+    }
+}
+
+impl<C> Clock for QuantizedClock<C>
+where
+    C: Clock,
+{
+    #[inline]
+    fn now(&self) -> Instant {
+        let offset = self.inner.now().saturating_duration_since(self.base);
+        self.grid_point(self.steps(offset))
+    }
+
+    /// Sleeps `inner` until the first grid point at or after `deadline`.
+    #[inline]
+    fn sleep_until(&self, deadline: Instant) {
+        let offset = deadline.saturating_duration_since(self.base);
+        let mut steps = self.steps(offset);
+        if self.grid_point(steps) < deadline {
+            steps += 1;
+        }
+        self.inner.sleep_until(self.grid_point(steps));
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::ClockExt;
+    use crate::testing::ManualClock;
+    use std::sync::Arc;
+
+    #[test]
+    fn floors_to_resolution() {
+        let inner = Arc::new(ManualClock::new());
+        let clock = QuantizedClock::new(Arc::clone(&inner), Duration::from_millis(1));
+        let start = clock.now();
+
+        inner.advance(Duration::from_micros(999));
+        assert_eq!(clock.now(), start);
+
+        inner.advance(Duration::from_micros(1));
+        assert_eq!(clock.now() - start, Duration::from_millis(1));
+
+        inner.advance(Duration::from_micros(2500));
+        assert_eq!(clock.now() - start, Duration::from_millis(3));
+    }
+
+    #[test]
+    fn sleep_reaches_grid_point() {
+        let inner = Arc::new(ManualClock::new());
+        let clock = QuantizedClock::new(Arc::clone(&inner), Duration::from_millis(10));
+        let start = clock.now();
+
+        clock.sleep(Duration::from_millis(15));
+        assert_eq!(clock.now() - start, Duration::from_millis(20));
+    }
+}
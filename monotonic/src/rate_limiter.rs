@@ -0,0 +1,103 @@
+use crate::{Clock, Instant};
+use std::sync::Mutex;
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+/// A token-bucket rate limiter.
+///
+/// The bucket starts full with `burst` tokens and refills continuously at
+/// `rate_per_sec`, never holding more than `burst`.
+#[derive(Debug)]
+pub struct RateLimiter<C>
+where
+    C: Clock,
+{
+    clock: C,
+    rate_per_sec: f64,
+    burst: u32,
+    bucket: Mutex<Bucket>,
+}
+
+impl<C> RateLimiter<C>
+where
+    C: Clock,
+{
+    #[inline]
+    pub fn new(clock: C, rate_per_sec: f64, burst: u32) -> Self {
+        assert!(
+            rate_per_sec.is_finite() && rate_per_sec >= 0.0,
+            "RateLimiter rate must be finite and non-negative, got {rate_per_sec}"
+        );
+
+        Self {
+            bucket: Mutex::new(Bucket {
+                tokens: f64::from(burst),
+                last_refill: clock.now(),
+            }),
+            clock,
+            rate_per_sec,
+            burst,
+        }
+    }
+
+    #[inline]
+    pub fn try_acquire(&self) -> bool {
+        let mut bucket = self.bucket.lock().unwrap();
+
+        let now = self.clock.now();
+        let refill = now
+            .saturating_duration_since(bucket.last_refill)
+            .as_secs_f64()
+            * self.rate_per_sec;
+        bucket.tokens = (bucket.tokens + refill).min(f64::from(self.burst));
+        bucket.last_refill = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ManualClock;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[test]
+    fn refills_with_time() {
+        let clock = Arc::new(ManualClock::new());
+        let limiter = RateLimiter::new(Arc::clone(&clock), 2.0, 3);
+
+        assert!((0..3).all(|_| limiter.try_acquire()));
+        assert!(!limiter.try_acquire());
+
+        clock.advance(Duration::from_millis(500));
+        assert!(limiter.try_acquire());
+        assert!(!limiter.try_acquire());
+
+        clock.advance(Duration::from_secs(1));
+        assert!((0..2).all(|_| limiter.try_acquire()));
+        assert!(!limiter.try_acquire());
+    }
+
+    #[test]
+    fn huge_advance_caps_at_burst() {
+        let clock = Arc::new(ManualClock::new());
+        let limiter = RateLimiter::new(Arc::clone(&clock), f64::MAX, 2);
+
+        assert!((0..2).all(|_| limiter.try_acquire()));
+        clock.advance(Duration::from_secs(100 * 365 * 24 * 60 * 60));
+
+        assert!((0..2).all(|_| limiter.try_acquire()));
+        assert!(!limiter.try_acquire());
+    }
+}
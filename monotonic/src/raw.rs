@@ -0,0 +1,66 @@
+use core::fmt::Debug;
+use core::hash::Hash;
+use core::time::Duration;
+
+/// A backend reading that [`Instant`](crate::Instant) wraps.
+///
+/// Each build selects one backend as `Inner`: `std::time::Instant` with
+/// `std`, a tick counter without. Everything `Instant` does beyond tracking
+/// clock sources goes through this trait, so adding a backend means
+/// implementing it and selecting the type.
+pub(crate) trait RawMonotonic: Copy + Ord + Hash + Debug {
+    fn checked_add(&self, duration: Duration) -> Option<Self>;
+
+    fn checked_sub(&self, duration: Duration) -> Option<Self>;
+
+    fn checked_duration_since(&self, earlier: Self) -> Option<Duration>;
+
+    #[inline]
+    fn saturating_duration_since(&self, earlier: Self) -> Duration {
+        self.checked_duration_since(earlier).unwrap_or_default()
+    }
+
+    #[inline]
+    fn add(&self, duration: Duration) -> Self {
+        self.checked_add(duration)
+            .expect("overflow when adding duration to instant")
+    }
+
+    #[inline]
+    fn sub(&self, duration: Duration) -> Self {
+        self.checked_sub(duration)
+            .expect("overflow when subtracting duration from instant")
+    }
+}
+
+/// A backend that can read the current time by itself.
+#[cfg(feature = "std")]
+pub(crate) trait RawClock: RawMonotonic {
+    fn raw_now() -> Self;
+}
+
+#[cfg(feature = "std")]
+impl RawMonotonic for std::time::Instant {
+    #[inline]
+    fn checked_add(&self, duration: Duration) -> Option<Self> {
+        std::time::Instant::checked_add(self, duration)
+    }
+
+    #[inline]
+    fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        std::time::Instant::checked_sub(self, duration)
+    }
+
+    #[inline]
+    fn checked_duration_since(&self, earlier: Self) -> Option<Duration> {
+        std::time::Instant::checked_duration_since(self, earlier)
+    }
+}
+
+#[cfg(feature = "std")]
+impl RawClock for std::time::Instant {
+    #[inline]
+    fn raw_now() -> Self {
+        std::time::Instant::now()
+    }
+}
@@ -0,0 +1,183 @@
+use crate::{Clock, Instant};
+use std::sync::Mutex;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy)]
+struct Anchor {
+    inner: Instant,
+    scaled: Instant,
+    scale: f64,
+}
+
+/// A clock that runs at `scale` times the rate of `clock`.
+///
+/// A scale of `0.0` freezes the clock, so [`sleep_until`](Clock::sleep_until)
+/// a later deadline blocks until [`set_scale`](Self::set_scale) unfreezes it.
+#[derive(Debug)]
+pub struct ScaledClock<C>
+where
+    C: Clock,
+{
+    clock: C,
+    anchor: Mutex<Anchor>,
+}
+
+impl<C> ScaledClock<C>
+where
+    C: Clock,
+{
+    #[inline]
+    pub fn new(clock: C, scale: f64) -> Self {
+        assert_valid_scale(scale);
+
+        let base = clock.now();
+        Self {
+            clock,
+            anchor: Mutex::new(Anchor {
+                inner: base,
+                scaled: base,
+                scale,
+            }),
+        }
+    }
+
+    #[inline]
+    pub fn scale(&self) -> f64 {
+        self.anchor.lock().unwrap().scale
+    }
+
+    /// Changes the scale factor from this point on.
+    ///
+    /// The clock is re-anchored at its current reading, so instants returned
+    /// after the change never precede those returned before it.
+    #[inline]
+    pub fn set_scale(&self, scale: f64) {
+        assert_valid_scale(scale);
+
+        let mut anchor = self.anchor.lock().unwrap();
+        let inner = self.clock.now();
+        *anchor = Anchor {
+            inner,
+            scaled: anchor.read(inner),
+            scale,
+        };
+    }
+}
+
+impl<C> Clock for ScaledClock<C>
+where
+    C: Clock,
+{
+    #[inline]
+    fn now(&self) -> Instant {
+        let anchor = self.anchor.lock().unwrap();
+        anchor.read(self.clock.now())
+    }
+
+    #[inline]
+    fn sleep_until(&self, deadline: Instant) {
+        loop {
+            let (now, scale) = {
+                let anchor = self.anchor.lock().unwrap();
+                (anchor.read(self.clock.now()), anchor.scale)
+            };
+            if now >= deadline {
+                return;
+            }
+
+            let remaining = deadline - now;
+
+            let inner_remaining = if scale > 0.0 {
+                scale_duration(remaining, scale.recip())
+            } else {
+                remaining
+            };
+            self.clock
+                .sleep_until(self.clock.now().saturating_add(inner_remaining));
+        }
+    }
+}
+
+impl Anchor {
+    #[inline]
+    fn read(&self, inner_now: Instant) -> Instant {
+        self.scaled.saturating_add(scale_duration(
+            inner_now.saturating_duration_since(self.inner),
+            self.scale,
+        ))
+    }
+}
+
+#[inline]
+fn assert_valid_scale(scale: f64) {
+    assert!(
+        scale.is_finite() && scale >= 0.0,
+        "ScaledClock scale must be finite and non-negative, got {scale}"
+    );
+}
+
+#[inline]
+fn scale_duration(duration: Duration, scale: f64) -> Duration {
+    Duration::try_from_secs_f64(duration.as_secs_f64() * scale).unwrap_or(Duration::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ClockExt;
+    use crate::testing::ManualClock;
+    use std::sync::Arc;
+
+    #[test]
+    fn scales_elapsed_time() {
+        let inner = Arc::new(ManualClock::new());
+        let fast = ScaledClock::new(Arc::clone(&inner), 10.0);
+        let slow = ScaledClock::new(Arc::clone(&inner), 0.5);
+        let frozen = ScaledClock::new(Arc::clone(&inner), 0.0);
+        let (fast_start, slow_start, frozen_start) = (fast.now(), slow.now(), frozen.now());
+
+        inner.advance(Duration::from_secs(2));
+
+        assert_eq!(fast.elapsed_since(fast_start), Duration::from_secs(20));
+        assert_eq!(slow.elapsed_since(slow_start), Duration::from_secs(1));
+        assert_eq!(frozen.now(), frozen_start);
+    }
+
+    #[test]
+    fn set_scale_keeps_time_monotonic() {
+        let inner = Arc::new(ManualClock::new());
+        let clock = ScaledClock::new(Arc::clone(&inner), 4.0);
+        let start = clock.now();
+
+        inner.advance(Duration::from_secs(1));
+        let before = clock.now();
+        clock.set_scale(0.25);
+        assert!(clock.now() >= before);
+
+        inner.advance(Duration::from_secs(4));
+        assert_eq!(clock.elapsed_since(start), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn sleep_waits_for_scaled_time() {
+        let clock = ScaledClock::new(crate::StdClock, 10.0);
+        let start = clock.now();
+
+        clock.sleep(Duration::from_millis(50));
+
+        assert!(clock.elapsed_since(start) >= Duration::from_millis(50));
+    }
+
+    #[test]
+    fn scaling_saturates() {
+        let inner = Arc::new(ManualClock::new());
+        let clock = ScaledClock::new(Arc::clone(&inner), 1e300);
+        let start = clock.now();
+
+        inner.advance(Duration::from_secs(1));
+        let now = clock.now();
+
+        assert!(now > start);
+        assert_eq!(clock.now(), now);
+    }
+}
@@ -0,0 +1,74 @@
+//! Serialization of [`Instant`]s relative to a reference instant.
+//!
+//! An [`Instant`] has no absolute representation: the underlying monotonic
+//! clock starts at an unspecified point that differs between processes and
+//! boots. Serializing one on its own is therefore impossible. Instead, an
+//! instant is stored as the [`Duration`] elapsed since a caller-supplied base,
+//! and deserialized by adding that offset back onto a base instant.
+//!
+//! Deserialized instants take the clock source of the base, so round-tripping
+//! against the same base yields an instant in the same clock domain.
+
+use crate::Instant;
+use ::serde::de::Error as _;
+use ::serde::ser::Error as _;
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use core::time::Duration;
+
+#[inline]
+pub fn serialize_relative_to<S>(
+    instant: &Instant,
+    base: Instant,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    instant
+        .checked_duration_since(base)
+        .ok_or_else(|| S::Error::custom("instant precedes the serialization base"))?
+        .serialize(serializer)
+}
+
+#[inline]
+pub fn deserialize_relative_to<'de, D>(base: Instant, deserializer: D) -> Result<Instant, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let offset = Duration::deserialize(deserializer)?;
+    base.checked_add(offset)
+        .ok_or_else(|| D::Error::custom("offset overflows the deserialization base"))
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{Clock, StdClock};
+
+    #[test]
+    fn round_trip() {
+        let base = StdClock.now();
+        let instant = base + Duration::from_millis(1500);
+
+        let mut json = Vec::new();
+        serialize_relative_to(&instant, base, &mut serde_json::Serializer::new(&mut json)).unwrap();
+        let restored =
+            deserialize_relative_to(base, &mut serde_json::Deserializer::from_slice(&json))
+                .unwrap();
+
+        assert_eq!(restored, instant);
+        assert_eq!(restored - base, Duration::from_millis(1500));
+    }
+
+    #[test]
+    fn instant_before_base_is_an_error() {
+        let base = StdClock.now() + Duration::from_secs(1);
+        let instant = base - Duration::from_secs(1);
+
+        let mut json = Vec::new();
+        assert!(
+            serialize_relative_to(&instant, base, &mut serde_json::Serializer::new(&mut json))
+                .is_err()
+        );
+    }
+}
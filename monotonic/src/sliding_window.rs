@@ -0,0 +1,121 @@
+use crate::{Clock, Instant};
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+#[derive(Debug)]
+struct Buckets {
+    counts: Vec<u64>,
+    /// Index of the bucket period that `counts` was last rotated to.
+    current: u128,
+}
+
+/// Counts events over a trailing window split into `bucket_count` buckets.
+///
+/// Buckets rotate as the clock advances, each covering `window /
+/// bucket_count`. The count includes the current, partially elapsed bucket,
+/// so it spans between `window - window / bucket_count` and `window`.
+#[derive(Debug)]
+pub struct SlidingWindowCounter<C>
+where
+    C: Clock,
+{
+    clock: C,
+    start: Instant,
+    bucket_width: Duration,
+    buckets: Mutex<Buckets>,
+}
+
+impl<C> SlidingWindowCounter<C>
+where
+    C: Clock,
+{
+    #[inline]
+    pub fn new(clock: C, window: Duration, bucket_count: usize) -> Self {
+        assert!(
+            bucket_count > 0,
+            "SlidingWindowCounter needs at least one bucket"
+        );
+        let bucket_width = window / u32::try_from(bucket_count).expect("too many buckets");
+        assert!(
+            !bucket_width.is_zero(),
+            "SlidingWindowCounter window must be at least one nanosecond per bucket"
+        );
+
+        Self {
+            start: clock.now(),
+            clock,
+            bucket_width,
+            buckets: Mutex::new(Buckets {
+                counts: vec![0; bucket_count],
+                current: 0,
+            }),
+        }
+    }
+
+    #[inline]
+    pub fn record(&self) {
+        let mut buckets = self.rotate();
+        let len = buckets.counts.len() as u128;
+        let index = (buckets.current % len) as usize;
+        buckets.counts[index] += 1;
+    }
+
+    #[inline]
+    pub fn count(&self) -> u64 {
+        self.rotate().counts.iter().sum()
+    }
+
+    fn rotate(&self) -> MutexGuard<'_, Buckets> {
+        let mut buckets = self.buckets.lock().unwrap();
+
+        let elapsed = self.clock.now().saturating_duration_since(self.start);
+        let period = elapsed.as_nanos() / self.bucket_width.as_nanos();
+        let len = buckets.counts.len() as u128;
+        let stale = period.saturating_sub(buckets.current).min(len);
+        for offset in 1..=stale {
+            let index = ((buckets.current + offset) % len) as usize;
+            buckets.counts[index] = 0;
+        }
+        buckets.current = buckets.current.max(period);
+
+        buckets
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ManualClock;
+    use std::sync::Arc;
+
+    #[test]
+    fn buckets_expire_as_time_advances() {
+        let clock = Arc::new(ManualClock::new());
+        let counter = SlidingWindowCounter::new(Arc::clone(&clock), Duration::from_secs(3), 3);
+
+        counter.record();
+        clock.advance(Duration::from_secs(1));
+        counter.record();
+        counter.record();
+        assert_eq!(counter.count(), 3);
+
+        clock.advance(Duration::from_secs(2));
+        assert_eq!(counter.count(), 2);
+
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(counter.count(), 0);
+    }
+
+    #[test]
+    fn long_gap_clears_everything() {
+        let clock = Arc::new(ManualClock::new());
+        let counter = SlidingWindowCounter::new(Arc::clone(&clock), Duration::from_secs(1), 4);
+
+        (0..10).for_each(|_| counter.record());
+        clock.advance(Duration::from_secs(3600));
+        assert_eq!(counter.count(), 0);
+
+        counter.record();
+        assert_eq!(counter.count(), 1);
+    }
+}
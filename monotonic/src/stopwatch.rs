@@ -0,0 +1,88 @@
+use crate::{Clock, ClockExt, Instant};
+use core::time::Duration;
+
+#[derive(Debug)]
+pub struct Stopwatch<C>
+where
+    C: Clock,
+{
+    clock: C,
+    accumulated: Duration,
+    resumed_at: Option<Instant>,
+}
+
+impl<C> Stopwatch<C>
+where
+    C: Clock,
+{
+    #[inline]
+    pub fn start(clock: C) -> Self {
+        Self {
+            resumed_at: Some(clock.now()),
+            clock,
+            accumulated: Duration::ZERO,
+        }
+    }
+
+    #[inline]
+    pub fn elapsed(&self) -> Duration {
+        match self.resumed_at {
+            Some(resumed_at) => self.accumulated + self.clock.elapsed_since(resumed_at),
+            None => self.accumulated,
+        }
+    }
+
+    #[inline]
+    pub fn restart(&mut self) {
+        self.accumulated = Duration::ZERO;
+        self.resumed_at = Some(self.clock.now());
+    }
+
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        self.resumed_at.is_none()
+    }
+
+    #[inline]
+    pub fn pause(&mut self) {
+        if !self.is_paused() {
+            self.accumulated = self.elapsed();
+            self.resumed_at = None;
+        }
+    }
+
+    #[inline]
+    pub fn resume(&mut self) {
+        if self.is_paused() {
+            self.resumed_at = Some(self.clock.now());
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::testing::ManualClock;
+    use std::sync::Arc;
+
+    #[test]
+    fn paused_intervals_do_not_count() {
+        let clock = Arc::new(ManualClock::new());
+        let mut stopwatch = Stopwatch::start(Arc::clone(&clock));
+
+        clock.advance(Duration::from_secs(1));
+        stopwatch.pause();
+        assert!(stopwatch.is_paused());
+
+        clock.advance(Duration::from_secs(10));
+        assert_eq!(stopwatch.elapsed(), Duration::from_secs(1));
+
+        stopwatch.resume();
+        clock.advance(Duration::from_secs(2));
+        assert_eq!(stopwatch.elapsed(), Duration::from_secs(3));
+
+        stopwatch.restart();
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(stopwatch.elapsed(), Duration::from_secs(1));
+    }
+}
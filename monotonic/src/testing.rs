@@ -0,0 +1,32 @@
+use crate::{Clock, Instant, StdClock};
+use std::sync::Mutex;
+use std::time::Duration;
+
+pub(crate) struct ManualClock {
+    epoch: Instant,
+    elapsed: Mutex<Duration>,
+}
+
+impl ManualClock {
+    pub(crate) fn new() -> Self {
+        Self {
+            epoch: StdClock::new_mock_epoch(),
+            elapsed: Mutex::new(Duration::ZERO),
+        }
+    }
+
+    pub(crate) fn advance(&self, duration: Duration) {
+        *self.elapsed.lock().unwrap() += duration;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.epoch + *self.elapsed.lock().unwrap()
+    }
+
+    fn sleep_until(&self, deadline: Instant) {
+        let mut elapsed = self.elapsed.lock().unwrap();
+        *elapsed = (*elapsed).max(deadline - self.epoch);
+    }
+}
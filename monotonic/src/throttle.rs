@@ -0,0 +1,99 @@
+use crate::{Clock, Instant};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Runs a closure at most once per `interval`, returning the cached result in
+/// between.
+///
+/// The lock is held while the closure runs, so concurrent callers wait for
+/// the fresh value instead of computing it again.
+#[derive(Debug)]
+pub struct Throttle<T, C>
+where
+    C: Clock,
+{
+    clock: C,
+    interval: Duration,
+    last: Mutex<Option<(Instant, T)>>,
+}
+
+impl<T, C> Throttle<T, C>
+where
+    T: Clone,
+    C: Clock,
+{
+    #[inline]
+    pub fn new(clock: C, interval: Duration) -> Self {
+        Self {
+            clock,
+            interval,
+            last: Mutex::new(None),
+        }
+    }
+
+    #[inline]
+    pub const fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Returns the cached value if `f` last ran less than `interval` ago, and
+    /// otherwise runs `f` and caches its result.
+    #[inline]
+    pub fn get_or_run<F>(&self, f: F) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        let mut last = self.last.lock().unwrap();
+
+        let now = self.clock.now();
+        if let Some((ran_at, value)) = &*last
+            && now.saturating_duration_since(*ran_at) < self.interval
+        {
+            return value.clone();
+        }
+
+        let value = f();
+        *last = Some((now, value.clone()));
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ManualClock;
+    use std::sync::Arc;
+
+    #[test]
+    fn recomputes_once_interval_elapses() {
+        let clock = Arc::new(ManualClock::new());
+        let throttle = Throttle::new(Arc::clone(&clock), Duration::from_secs(1));
+        let mut runs = 0;
+
+        assert_eq!(
+            throttle.get_or_run(|| {
+                runs += 1;
+                runs
+            }),
+            1
+        );
+        clock.advance(Duration::from_millis(999));
+        assert_eq!(
+            throttle.get_or_run(|| {
+                runs += 1;
+                runs
+            }),
+            1
+        );
+
+        clock.advance(Duration::from_millis(1));
+        assert_eq!(
+            throttle.get_or_run(|| {
+                runs += 1;
+                runs
+            }),
+            2
+        );
+        assert_eq!(runs, 2);
+    }
+}
@@ -0,0 +1,113 @@
+use crate::raw::RawMonotonic;
+use core::ops::{Add, Sub};
+use core::time::Duration;
+
+/// Frequency of the tick counter backing [`Instant`](crate::Instant) in
+/// `no_std` builds.
+///
+/// Set at build time through the `MONOTONIC_TICK_HZ` environment variable and
+/// defaults to 1 MHz. Conversions between ticks and [`Duration`]s truncate
+/// towards zero when the frequency does not evenly divide a nanosecond count.
+pub const TICK_HZ: u64 = match option_env!("MONOTONIC_TICK_HZ") {
+    Some(hz) => parse_hz(hz),
+    None => 1_000_000,
+};
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+const fn parse_hz(hz: &str) -> u64 {
+    let bytes = hz.as_bytes();
+    assert!(!bytes.is_empty(), "MONOTONIC_TICK_HZ must not be empty");
+
+    let mut value: u64 = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let digit = bytes[i];
+        assert!(
+            digit.is_ascii_digit(),
+            "MONOTONIC_TICK_HZ must be a decimal integer"
+        );
+        value = value * 10 + (digit - b'0') as u64;
+        i += 1;
+    }
+
+    assert!(value > 0, "MONOTONIC_TICK_HZ must be non-zero");
+    value
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct Ticks(pub(crate) u64);
+
+impl RawMonotonic for Ticks {
+    #[inline]
+    fn checked_add(&self, duration: Duration) -> Option<Ticks> {
+        self.0.checked_add(duration_to_ticks(duration)?).map(Ticks)
+    }
+
+    #[inline]
+    fn checked_sub(&self, duration: Duration) -> Option<Ticks> {
+        self.0.checked_sub(duration_to_ticks(duration)?).map(Ticks)
+    }
+
+    #[inline]
+    fn checked_duration_since(&self, earlier: Ticks) -> Option<Duration> {
+        self.0.checked_sub(earlier.0).map(ticks_to_duration)
+    }
+}
+
+impl Add<Duration> for Ticks {
+    type Output = Ticks;
+
+    #[inline]
+    fn add(self, rhs: Duration) -> Self::Output {
+        RawMonotonic::add(&self, rhs)
+    }
+}
+
+impl Sub<Duration> for Ticks {
+    type Output = Ticks;
+
+    #[inline]
+    fn sub(self, rhs: Duration) -> Self::Output {
+        RawMonotonic::sub(&self, rhs)
+    }
+}
+
+impl Sub for Ticks {
+    type Output = Duration;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.saturating_duration_since(rhs)
+    }
+}
+
+#[inline]
+fn ticks_to_duration(ticks: u64) -> Duration {
+    let hz = TICK_HZ as u128;
+    let secs = ticks as u128 / hz;
+    let nanos = (ticks as u128 % hz) * NANOS_PER_SEC / hz;
+    Duration::new(secs as u64, nanos as u32)
+}
+
+#[inline]
+fn duration_to_ticks(duration: Duration) -> Option<u64> {
+    u64::try_from(duration.as_nanos() * TICK_HZ as u128 / NANOS_PER_SEC).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic_round_trips_through_ticks() {
+        let start = Ticks(42);
+        let later = start + Duration::from_millis(1500);
+
+        assert_eq!(later - start, Duration::from_millis(1500));
+        assert_eq!(later - Duration::from_millis(1500), start);
+        assert_eq!(start - later, Duration::ZERO);
+        assert_eq!(start.checked_duration_since(later), None);
+        assert_eq!(Ticks(u64::MAX).checked_add(Duration::from_secs(1)), None);
+    }
+}
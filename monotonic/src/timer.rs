@@ -0,0 +1,75 @@
+use crate::{Clock, Deadline, Instant};
+use core::time::Duration;
+
+#[derive(Debug)]
+pub struct Timer<C>
+where
+    C: Clock,
+{
+    clock: C,
+    deadline: Deadline,
+    fired: bool,
+}
+
+impl<C> Timer<C>
+where
+    C: Clock,
+{
+    #[inline]
+    pub fn new(clock: C, duration: Duration) -> Self {
+        Self {
+            deadline: Deadline::after(&clock, duration),
+            clock,
+            fired: false,
+        }
+    }
+
+    #[inline]
+    pub fn deadline(&self) -> Instant {
+        self.deadline.instant()
+    }
+
+    #[inline]
+    pub fn has_fired(&self) -> bool {
+        self.deadline.is_expired(&self.clock)
+    }
+
+    /// Returns `true` on the first call made at or after the deadline and
+    /// `false` on every other call.
+    #[inline]
+    pub fn poll(&mut self) -> bool {
+        if self.fired || !self.has_fired() {
+            return false;
+        }
+
+        self.fired = true;
+        true
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::testing::ManualClock;
+    use std::sync::Arc;
+
+    #[test]
+    fn polls_true_exactly_once() {
+        let clock = Arc::new(ManualClock::new());
+        let mut timer = Timer::new(Arc::clone(&clock), Duration::from_secs(2));
+
+        assert!(!timer.poll());
+        clock.advance(Duration::from_secs(1));
+        assert!(!timer.has_fired());
+        assert!(!timer.poll());
+
+        clock.advance(Duration::from_secs(1));
+        assert!(timer.has_fired());
+        assert!(timer.poll());
+        assert!(!timer.poll());
+
+        clock.advance(Duration::from_secs(1));
+        assert!(timer.has_fired());
+        assert!(!timer.poll());
+    }
+}
@@ -0,0 +1,65 @@
+use crate::Instant;
+
+impl Instant {
+    /// Converts to a [`tokio::time::Instant`].
+    ///
+    /// Only instants read from [`StdClock`](crate::StdClock) correspond to
+    /// tokio's clock; converting one from a mock clock is a bug, caught when
+    /// clock sources are tracked.
+    #[inline]
+    pub fn to_tokio(&self) -> ::tokio::time::Instant {
+        #[cfg(any(debug_assertions, feature = "track-source"))]
+        assert!(
+            self.source == crate::ClockSource::Std,
+            "only std clock instants can be converted to tokio instants, got {:?}",
+            self.source
+        );
+
+        ::tokio::time::Instant::from_std(self.as_std())
+    }
+
+    #[inline]
+    pub fn from_tokio(instant: ::tokio::time::Instant) -> Instant {
+        Instant::from_std(instant.into_std())
+    }
+}
+
+impl From<::tokio::time::Instant> for Instant {
+    #[inline]
+    fn from(instant: ::tokio::time::Instant) -> Self {
+        Instant::from_tokio(instant)
+    }
+}
+
+impl From<Instant> for ::tokio::time::Instant {
+    #[inline]
+    fn from(instant: Instant) -> Self {
+        instant.to_tokio()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Clock, StdClock};
+    use std::time::Duration;
+
+    #[test]
+    fn round_trip() {
+        let now = StdClock.now();
+
+        let tokio: ::tokio::time::Instant = now.into();
+        assert_eq!(Instant::from(tokio), now);
+        assert_eq!(
+            Instant::from_tokio(tokio + Duration::from_secs(1)) - now,
+            Duration::from_secs(1)
+        );
+    }
+
+    #[cfg(any(debug_assertions, feature = "track-source"))]
+    #[test]
+    #[should_panic]
+    fn mock_instant_panics() {
+        crate::testing::ManualClock::new().now().to_tokio();
+    }
+}
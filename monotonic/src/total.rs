@@ -0,0 +1,67 @@
+use crate::Instant;
+use core::time::Duration;
+
+/// Sums the length of each `(start, end)` span.
+///
+/// Each span is measured with [`Instant::duration_since`], so both ends must
+/// share a clock source. The total saturates at [`Duration::MAX`] instead of
+/// overflowing.
+#[inline]
+pub fn total_elapsed<I>(spans: I) -> Duration
+where
+    I: IntoIterator<Item = (Instant, Instant)>,
+{
+    spans
+        .into_iter()
+        .map(|(start, end)| end.duration_since(start))
+        .total()
+}
+
+mod private {
+    pub trait Sealed {}
+    impl<I> Sealed for I where I: Iterator<Item = core::time::Duration> {}
+}
+
+pub trait DurationExt: Iterator<Item = Duration> + private::Sealed {
+    /// Sums the durations, saturating at [`Duration::MAX`] instead of
+    /// panicking on overflow like [`Sum`](core::iter::Sum) does.
+    fn total(self) -> Duration;
+}
+
+impl<I> DurationExt for I
+where
+    I: Iterator<Item = Duration>,
+{
+    #[inline]
+    fn total(self) -> Duration {
+        self.fold(Duration::ZERO, Duration::saturating_add)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{Clock, StdClock};
+
+    #[test]
+    fn sums_spans() {
+        let start = StdClock.now();
+        let spans = [
+            (start, start + Duration::from_secs(1)),
+            (
+                start + Duration::from_secs(5),
+                start + Duration::from_secs(7),
+            ),
+        ];
+
+        assert_eq!(total_elapsed(spans), Duration::from_secs(3));
+        assert_eq!(total_elapsed([]), Duration::ZERO);
+    }
+
+    #[test]
+    fn total_saturates() {
+        let durations = [Duration::MAX, Duration::from_secs(1)];
+
+        assert_eq!(durations.into_iter().total(), Duration::MAX);
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{Clock, Instant};
+use core::borrow::Borrow;
+use core::hash::Hash;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// A map whose entries expire a fixed time after insertion.
+///
+/// An entry is expired once the clock reaches its expiry instant. Expired
+/// entries are hidden from [`get`](Self::get) but only freed by
+/// [`purge_expired`](Self::purge_expired).
+#[derive(Debug)]
+pub struct TtlCache<K, V, C>
+where
+    C: Clock,
+{
+    clock: C,
+    entries: HashMap<K, (V, Instant)>,
+}
+
+impl<K, V, C> TtlCache<K, V, C>
+where
+    K: Eq + Hash,
+    C: Clock,
+{
+    #[inline]
+    pub fn new(clock: C) -> Self {
+        Self {
+            clock,
+            entries: HashMap::new(),
+        }
+    }
+
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V, ttl: Duration) {
+        let expiry = self.clock.now().saturating_add(ttl);
+        self.entries.insert(key, (value, expiry));
+    }
+
+    #[inline]
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let (value, expiry) = self.entries.get(key)?;
+        (*expiry > self.clock.now()).then_some(value)
+    }
+
+    #[inline]
+    pub fn purge_expired(&mut self) {
+        let now = self.clock.now();
+        self.entries.retain(|_, (_, expiry)| *expiry > now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ManualClock;
+    use std::sync::Arc;
+
+    #[test]
+    fn expires_at_the_boundary() {
+        let clock = Arc::new(ManualClock::new());
+        let mut cache = TtlCache::new(Arc::clone(&clock));
+        cache.insert("a", 1, Duration::from_secs(2));
+
+        clock.advance(Duration::from_secs(2) - Duration::from_nanos(1));
+        assert_eq!(cache.get("a"), Some(&1));
+
+        clock.advance(Duration::from_nanos(1));
+        assert_eq!(cache.get("a"), None);
+    }
+
+    #[test]
+    fn purge_removes_only_expired() {
+        let clock = Arc::new(ManualClock::new());
+        let mut cache = TtlCache::new(Arc::clone(&clock));
+        cache.insert("short", 1, Duration::from_secs(1));
+        cache.insert("long", 2, Duration::from_secs(10));
+
+        clock.advance(Duration::from_secs(1));
+        cache.purge_expired();
+        assert_eq!(cache.entries.len(), 1);
+        assert_eq!(cache.get("long"), Some(&2));
+
+        cache.insert("short", 3, Duration::from_secs(1));
+        assert_eq!(cache.get("short"), Some(&3));
+    }
+}
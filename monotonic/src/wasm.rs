@@ -0,0 +1,43 @@
+use crate::raw::{RawClock, RawMonotonic};
+use core::time::Duration;
+use wasm_bindgen::JsCast;
+
+thread_local! {
+    static PERFORMANCE: web_sys::Performance = js_sys::Reflect::get(
+        &js_sys::global(),
+        &"performance".into(),
+    )
+    .expect("performance is not available in this JavaScript environment")
+    .unchecked_into();
+}
+
+/// The backend reading on `wasm32-unknown-unknown`, where
+/// `std::time::Instant` is unsupported: time since the page or worker's
+/// `performance.timeOrigin`, read with `performance.now()`.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct PerformanceInstant(Duration);
+
+impl RawMonotonic for PerformanceInstant {
+    #[inline]
+    fn checked_add(&self, duration: Duration) -> Option<Self> {
+        self.0.checked_add(duration).map(Self)
+    }
+
+    #[inline]
+    fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        self.0.checked_sub(duration).map(Self)
+    }
+
+    #[inline]
+    fn checked_duration_since(&self, earlier: Self) -> Option<Duration> {
+        self.0.checked_sub(earlier.0)
+    }
+}
+
+impl RawClock for PerformanceInstant {
+    #[inline]
+    fn raw_now() -> Self {
+        let millis = PERFORMANCE.with(web_sys::Performance::now);
+        Self(Duration::from_secs_f64(millis.max(0.0) / 1_000.0))
+    }
+}
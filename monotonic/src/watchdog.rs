@@ -0,0 +1,95 @@
+use crate::{Clock, Instant};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Expires unless fed at least once every `timeout`.
+#[derive(Debug)]
+pub struct Watchdog<C>
+where
+    C: Clock,
+{
+    clock: C,
+    timeout: Duration,
+    deadline: Mutex<Instant>,
+}
+
+impl<C> Watchdog<C>
+where
+    C: Clock,
+{
+    #[inline]
+    pub fn new(clock: C, timeout: Duration) -> Self {
+        Self {
+            deadline: Mutex::new(clock.now().saturating_add(timeout)),
+            clock,
+            timeout,
+        }
+    }
+
+    #[inline]
+    pub const fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    #[inline]
+    pub fn deadline(&self) -> Instant {
+        *self.deadline.lock().unwrap()
+    }
+
+    /// Pushes the deadline back to `timeout` from now.
+    #[inline]
+    pub fn feed(&self) {
+        let deadline = self.clock.now().saturating_add(self.timeout);
+        *self.deadline.lock().unwrap() = deadline;
+    }
+
+    /// Returns whether the deadline has passed without a feed.
+    #[inline]
+    pub fn check(&self) -> bool {
+        self.clock.now() >= self.deadline()
+    }
+
+    /// Calls `on_expire` if the watchdog has expired, and returns whether it
+    /// had.
+    #[inline]
+    pub fn poll<F>(&self, on_expire: F) -> bool
+    where
+        F: FnOnce(),
+    {
+        let expired = self.check();
+        if expired {
+            on_expire();
+        }
+
+        expired
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ManualClock;
+    use std::sync::Arc;
+
+    #[test]
+    fn fires_unless_fed() {
+        let clock = Arc::new(ManualClock::new());
+        let watchdog = Watchdog::new(Arc::clone(&clock), Duration::from_secs(5));
+
+        clock.advance(Duration::from_secs(4));
+        assert!(!watchdog.check());
+        watchdog.feed();
+
+        clock.advance(Duration::from_secs(4));
+        assert!(!watchdog.poll(|| panic!("fed watchdog fired")));
+
+        let mut fired = false;
+        clock.advance(Duration::from_secs(1));
+        assert!(watchdog.poll(|| fired = true));
+        assert!(fired);
+
+        watchdog.feed();
+        assert!(!watchdog.check());
+        assert_eq!(watchdog.deadline(), clock.now() + Duration::from_secs(5));
+    }
+}
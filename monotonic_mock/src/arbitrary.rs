@@ -0,0 +1,91 @@
+use crate::MockClock;
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+use std::time::Duration;
+
+/// A single fuzzer-generated advance of at most [`MockAdvance::MAX`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MockAdvance(pub Duration);
+
+impl MockAdvance {
+    /// The largest generated advance, keeping long schedules far from
+    /// overflowing the clock.
+    pub const MAX: Duration = Duration::from_secs(24 * 60 * 60);
+
+    #[inline]
+    pub fn apply(&self, clock: &MockClock) {
+        clock.advance(self.0);
+    }
+}
+
+impl<'a> Arbitrary<'a> for MockAdvance {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let nanos = u.int_in_range(0..=Self::MAX.as_nanos() as u64)?;
+        Ok(Self(Duration::from_nanos(nanos)))
+    }
+
+    #[inline]
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (8, Some(8))
+    }
+}
+
+/// A fuzzer-generated sequence of advances to replay against a
+/// [`MockClock`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct AdvanceSchedule(pub Vec<MockAdvance>);
+
+impl<'a> Arbitrary<'a> for AdvanceSchedule {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.arbitrary_iter()?.collect::<Result<_>>().map(Self)
+    }
+
+    #[inline]
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        u.arbitrary_take_rest_iter()?
+            .collect::<Result<_>>()
+            .map(Self)
+    }
+}
+
+impl AdvanceSchedule {
+    #[inline]
+    pub fn total(&self) -> Duration {
+        self.0.iter().map(|advance| advance.0).sum()
+    }
+
+    /// Applies each advance in turn, calling `after_each` after every one.
+    #[inline]
+    pub fn apply<F>(&self, clock: &MockClock, after_each: F)
+    where
+        F: FnMut(&MockClock),
+    {
+        let advances: Vec<_> = self.0.iter().map(|advance| advance.0).collect();
+        clock.apply_advances(&advances, after_each);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schedules_stay_bounded() {
+        let bytes: Vec<u8> = (0..=255).cycle().take(4096).collect();
+        let mut u = Unstructured::new(&bytes);
+        let schedule = AdvanceSchedule::arbitrary(&mut u).unwrap();
+        assert!(
+            schedule
+                .0
+                .iter()
+                .all(|advance| advance.0 <= MockAdvance::MAX)
+        );
+
+        let clock = MockClock::new();
+        let mut steps = 0;
+        schedule.apply(&clock, |_| steps += 1);
+        assert_eq!(steps, schedule.0.len());
+        assert_eq!(clock.elapsed(), schedule.total());
+    }
+}
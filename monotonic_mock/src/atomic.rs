@@ -0,0 +1,122 @@
+use monotonic::{Clock, Instant, StdClock};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// A lock-free alternative to [`MockClock`](crate::MockClock).
+///
+/// Elapsed time is kept as nanoseconds in an [`AtomicU64`], so reads never
+/// contend. This caps the total elapsed time at `u64::MAX` nanoseconds, about
+/// 584 years; advancing past that panics.
+#[derive(Debug)]
+pub struct AtomicMockClock {
+    local_epoch: Instant,
+    elapsed_nanos: AtomicU64,
+}
+
+impl Default for AtomicMockClock {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            local_epoch: StdClock::new_mock_epoch(),
+            elapsed_nanos: AtomicU64::new(0),
+        }
+    }
+}
+
+impl AtomicMockClock {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn new_shared() -> Arc<AtomicMockClock> {
+        Arc::new(AtomicMockClock::new())
+    }
+
+    /// # Panics
+    ///
+    /// Panics, leaving the clock unchanged, if the total elapsed time would
+    /// exceed `u64::MAX` nanoseconds (about 584 years).
+    #[inline]
+    pub fn advance(&self, duration: Duration) {
+        let nanos = u64::try_from(duration.as_nanos())
+            .expect("AtomicMockClock cannot advance by more than u64::MAX nanoseconds");
+        self.elapsed_nanos
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |elapsed| {
+                elapsed.checked_add(nanos)
+            })
+            .expect("AtomicMockClock elapsed time overflowed u64::MAX nanoseconds");
+    }
+
+    #[inline]
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.elapsed_nanos.load(Ordering::Relaxed))
+    }
+}
+
+impl Clock for AtomicMockClock {
+    #[inline]
+    fn now(&self) -> Instant {
+        self.local_epoch + self.elapsed()
+    }
+
+    #[inline]
+    fn sleep_until(&self, deadline: Instant) {
+        while self.now() < deadline {
+            std::thread::yield_now();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use monotonic::ClockExt;
+
+    #[test]
+    fn advances_from_many_threads() {
+        let clock = AtomicMockClock::new_shared();
+        let start = clock.now();
+
+        let threads: Vec<_> = (0..4)
+            .map(|_| {
+                let clock = Arc::clone(&clock);
+                std::thread::spawn(move || {
+                    for _ in 0..100 {
+                        clock.advance(Duration::from_millis(1));
+                        let _ = clock.now();
+                    }
+                })
+            })
+            .collect();
+
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        assert_eq!(clock.elapsed_since(start), Duration::from_millis(400));
+        assert_eq!(clock.elapsed(), Duration::from_millis(400));
+    }
+
+    #[test]
+    #[should_panic]
+    fn overflow_panics() {
+        let clock = AtomicMockClock::new();
+
+        clock.advance(Duration::from_nanos(u64::MAX));
+        clock.advance(Duration::from_nanos(1));
+    }
+
+    #[test]
+    fn overflow_leaves_time_unchanged() {
+        let clock = AtomicMockClock::new();
+        clock.advance(Duration::from_nanos(u64::MAX - 1));
+
+        let overflowed =
+            std::panic::catch_unwind(|| clock.advance(Duration::from_nanos(2))).is_err();
+        assert!(overflowed);
+        assert_eq!(clock.elapsed(), Duration::from_nanos(u64::MAX - 1));
+    }
+}
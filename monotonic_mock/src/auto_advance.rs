@@ -0,0 +1,83 @@
+use monotonic::{Clock, Instant, StdClock};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+#[derive(Debug)]
+pub struct AutoAdvanceClock {
+    local_epoch: Instant,
+    elapsed: Mutex<Duration>,
+    step_nanos: AtomicU64,
+}
+
+impl AutoAdvanceClock {
+    #[inline]
+    pub fn new(step: Duration) -> Self {
+        Self {
+            local_epoch: StdClock::new_mock_epoch(),
+            elapsed: Mutex::new(Duration::ZERO),
+            step_nanos: AtomicU64::new(step_to_nanos(step)),
+        }
+    }
+
+    #[inline]
+    pub fn step(&self) -> Duration {
+        Duration::from_nanos(self.step_nanos.load(Ordering::Relaxed))
+    }
+
+    #[inline]
+    pub fn set_step(&self, step: Duration) {
+        self.step_nanos
+            .store(step_to_nanos(step), Ordering::Relaxed);
+    }
+}
+
+impl Clock for AutoAdvanceClock {
+    #[inline]
+    fn now(&self) -> Instant {
+        let mut elapsed = self.elapsed.lock().unwrap();
+        *elapsed += self.step();
+        self.local_epoch + *elapsed
+    }
+
+    /// Jumps straight to `deadline`, since nothing else drives this clock.
+    #[inline]
+    fn sleep_until(&self, deadline: Instant) {
+        let mut elapsed = self.elapsed.lock().unwrap();
+        *elapsed = (*elapsed).max(deadline - self.local_epoch);
+    }
+}
+
+#[inline]
+fn step_to_nanos(step: Duration) -> u64 {
+    u64::try_from(step.as_nanos()).expect("AutoAdvanceClock step exceeds u64::MAX nanoseconds")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use monotonic::ClockExt;
+
+    #[test]
+    fn every_read_advances() {
+        let clock = AutoAdvanceClock::new(Duration::from_millis(1));
+
+        let first = clock.now();
+        let second = clock.now();
+        assert_eq!(second - first, Duration::from_millis(1));
+
+        clock.set_step(Duration::from_secs(1));
+        assert_eq!(clock.step(), Duration::from_secs(1));
+        assert_eq!(clock.now() - second, Duration::from_secs(1));
+    }
+
+    #[test]
+    fn sleep_jumps_to_deadline() {
+        let clock = AutoAdvanceClock::new(Duration::ZERO);
+        let start = clock.now();
+
+        clock.sleep(Duration::from_secs(5));
+
+        assert_eq!(clock.now() - start, Duration::from_secs(5));
+    }
+}
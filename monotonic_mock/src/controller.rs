@@ -0,0 +1,159 @@
+use crate::MockClock;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::mpsc::{self, Receiver, Sender, SyncSender};
+use std::time::Duration;
+
+/// A change to apply to a [`ClockController`]'s clock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClockCommand {
+    Advance(Duration),
+    /// Like [`MockClock::set_elapsed`]; moving time backward is rejected.
+    SetElapsed(Duration),
+}
+
+struct Request {
+    step: u64,
+    command: ClockCommand,
+    ack: SyncSender<Duration>,
+}
+
+/// Applies commands from many threads to one [`MockClock`] in a fixed order.
+///
+/// Each command is submitted through a [`ControllerHandle`] with a step
+/// number. A background thread applies step 0 first, then step 1 and so on,
+/// holding back commands that arrive early, so the timeline is the same no
+/// matter how the submitting threads are scheduled. The thread exits once
+/// the controller and all its handles are dropped.
+#[derive(Debug)]
+pub struct ClockController {
+    clock: Arc<MockClock>,
+    handle: ControllerHandle,
+}
+
+impl ClockController {
+    #[inline]
+    pub fn new() -> Self {
+        let clock = MockClock::new_shared();
+        let (tx, rx) = mpsc::channel();
+
+        let worker_clock = Arc::clone(&clock);
+        std::thread::spawn(move || run(&worker_clock, rx));
+
+        Self {
+            clock,
+            handle: ControllerHandle { tx },
+        }
+    }
+
+    /// Returns the controlled clock, for reading. Advancing it directly
+    /// bypasses the command order.
+    #[inline]
+    pub fn clock(&self) -> &Arc<MockClock> {
+        &self.clock
+    }
+
+    #[inline]
+    pub fn handle(&self) -> ControllerHandle {
+        self.handle.clone()
+    }
+}
+
+impl Default for ClockController {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Submits commands to a [`ClockController`] from any thread.
+#[derive(Debug, Clone)]
+pub struct ControllerHandle {
+    tx: Sender<Request>,
+}
+
+impl ControllerHandle {
+    /// Blocks until `command` has been applied as `step`, after every earlier
+    /// step, and returns the clock's elapsed time right after it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` was already submitted, or if `command` would move
+    /// time backward.
+    #[inline]
+    pub fn submit(&self, step: u64, command: ClockCommand) -> Duration {
+        let (ack, acked) = mpsc::sync_channel(1);
+        self.tx
+            .send(Request { step, command, ack })
+            .expect("ClockController stopped");
+
+        acked
+            .recv()
+            .unwrap_or_else(|_| panic!("ClockController rejected step {step} ({command:?})"))
+    }
+}
+
+fn run(clock: &MockClock, requests: Receiver<Request>) {
+    let mut pending = BTreeMap::new();
+    let mut next_step = 0;
+
+    for request in requests {
+        // Dropping a rejected request's `ack` makes its submitter panic.
+        if request.step < next_step || pending.contains_key(&request.step) {
+            continue;
+        }
+        pending.insert(request.step, request);
+
+        while let Some(request) = pending.remove(&next_step) {
+            next_step += 1;
+            match request.command {
+                ClockCommand::Advance(duration) => clock.advance(duration),
+                ClockCommand::SetElapsed(elapsed) if elapsed >= clock.elapsed() => {
+                    clock.set_elapsed(elapsed)
+                }
+                ClockCommand::SetElapsed(_) => continue,
+            }
+
+            let _ = request.ack.send(clock.elapsed());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn applies_steps_in_order() {
+        let controller = ClockController::new();
+
+        let threads: Vec<_> = [
+            (2, ClockCommand::Advance(Duration::from_secs(1))),
+            (0, ClockCommand::Advance(Duration::from_secs(2))),
+            (1, ClockCommand::SetElapsed(Duration::from_secs(10))),
+        ]
+        .into_iter()
+        .map(|(step, command)| {
+            let handle = controller.handle();
+            std::thread::spawn(move || (step, handle.submit(step, command)))
+        })
+        .collect();
+
+        let mut results: Vec<_> = threads.into_iter().map(|t| t.join().unwrap()).collect();
+        results.sort();
+        assert_eq!(
+            results,
+            [(0, 2), (1, 10), (2, 11)].map(|(step, secs)| (step, Duration::from_secs(secs)))
+        );
+        assert_eq!(controller.clock().elapsed(), Duration::from_secs(11));
+    }
+
+    #[test]
+    #[should_panic(expected = "rejected step 1")]
+    fn backward_set_is_rejected() {
+        let handle = ClockController::new().handle();
+
+        handle.submit(0, ClockCommand::Advance(Duration::from_secs(5)));
+        handle.submit(1, ClockCommand::SetElapsed(Duration::from_secs(1)));
+    }
+}
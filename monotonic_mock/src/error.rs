@@ -0,0 +1,36 @@
+use std::fmt;
+use std::time::Duration;
+
+/// Advancing a [`MockClock`](crate::MockClock) would have carried it past
+/// the latest representable [`Instant`](monotonic::Instant).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClockOverflow {
+    pub(crate) elapsed: Duration,
+    pub(crate) by: Duration,
+}
+
+impl ClockOverflow {
+    /// Returns the clock's elapsed time when the advance was attempted.
+    #[inline]
+    pub const fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns the duration the clock was asked to advance by.
+    #[inline]
+    pub const fn by(&self) -> Duration {
+        self.by
+    }
+}
+
+impl fmt::Display for ClockOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "advancing MockClock by {:?} from {:?} elapsed overflows Instant",
+            self.by, self.elapsed
+        )
+    }
+}
+
+impl std::error::Error for ClockOverflow {}
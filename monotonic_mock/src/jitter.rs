@@ -0,0 +1,114 @@
+use monotonic::{Clock, Instant};
+use std::sync::Mutex;
+use std::time::Duration;
+
+#[derive(Debug)]
+struct State {
+    rng: u64,
+    last: Option<Instant>,
+}
+
+/// Adds up to `max_jitter` of seeded random delay to each reading of `inner`.
+///
+/// Readings never go backward: a reading that would land before the previous
+/// one repeats the previous one instead. The same seed over the same inner
+/// readings yields the same sequence.
+#[derive(Debug)]
+pub struct JitterClock<C>
+where
+    C: Clock,
+{
+    inner: C,
+    max_jitter: Duration,
+    state: Mutex<State>,
+}
+
+impl<C> JitterClock<C>
+where
+    C: Clock,
+{
+    #[inline]
+    pub fn new(inner: C, max_jitter: Duration, seed: u64) -> Self {
+        Self {
+            inner,
+            max_jitter,
+            state: Mutex::new(State {
+                rng: seed,
+                last: None,
+            }),
+        }
+    }
+
+    #[inline]
+    pub const fn max_jitter(&self) -> Duration {
+        self.max_jitter
+    }
+}
+
+impl<C> Clock for JitterClock<C>
+where
+    C: Clock,
+{
+    #[inline]
+    fn now(&self) -> Instant {
+        let mut state = self.state.lock().unwrap();
+
+        let jitter_nanos =
+            u128::from(splitmix64(&mut state.rng)) % (self.max_jitter.as_nanos() + 1);
+        let jitter = Duration::from_nanos(jitter_nanos as u64);
+        let mut now = self.inner.now().saturating_add(jitter);
+        if let Some(last) = state.last {
+            now = now.max(last);
+        }
+        state.last = Some(now);
+
+        now
+    }
+
+    #[inline]
+    fn sleep_until(&self, deadline: Instant) {
+        self.inner.sleep_until(deadline);
+    }
+}
+
+#[inline]
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MockClock;
+    use std::sync::Arc;
+
+    fn readings(seed: u64) -> Vec<Duration> {
+        let mock = MockClock::new_shared();
+        let start = mock.now();
+        let clock = JitterClock::new(Arc::clone(&mock), Duration::from_millis(10), seed);
+
+        (0..100)
+            .map(|_| {
+                mock.advance(Duration::from_millis(1));
+                clock.now() - start
+            })
+            .collect()
+    }
+
+    #[test]
+    fn bounded_monotonic_and_reproducible() {
+        let first = readings(42);
+
+        assert!(first.windows(2).all(|pair| pair[0] <= pair[1]));
+        for (i, reading) in first.iter().enumerate() {
+            let base = Duration::from_millis(i as u64 + 1);
+            assert!(*reading >= base && *reading <= base + Duration::from_millis(10));
+        }
+        assert_eq!(readings(42), first);
+        assert_ne!(readings(7), first);
+    }
+}
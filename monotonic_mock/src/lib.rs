@@ -1,26 +1,70 @@
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+mod atomic;
+mod auto_advance;
+mod controller;
+mod error;
+mod jitter;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+mod replay;
+mod schedule;
+mod shared;
+mod sleep;
+
+#[cfg(feature = "arbitrary")]
+pub use arbitrary::{AdvanceSchedule, MockAdvance};
+pub use atomic::AtomicMockClock;
+pub use auto_advance::AutoAdvanceClock;
+pub use controller::{ClockCommand, ClockController, ControllerHandle};
+pub use error::ClockOverflow;
+pub use jitter::JitterClock;
+pub use replay::ReplayClock;
+pub use schedule::{Callback, Schedule};
+pub use shared::SharedMockClock;
+
+use schedule::CallbackQueue;
+
 use monotonic::{Clock, Instant, StdClock};
-use std::sync::{Arc, Mutex};
-use std::time::Duration;
+use std::sync::{Arc, Condvar, Mutex};
+use std::task::Waker;
+use std::time::{Duration, SystemTime};
+
+/// How an advance wakes threads blocked in [`MockClock::wait_until`].
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq)]
+pub enum WakeOrder {
+    /// Wake every waiter at once when the clock moves, leaving the order in
+    /// which waiters past their deadline return up to the OS scheduler.
+    #[default]
+    NotifyAll,
+    /// Stop at each waiter's deadline on the way, like a scheduled callback,
+    /// and wait for the waiters due there to return before moving on. A
+    /// waiter on an earlier deadline thus returns, having observed `now()`
+    /// at its deadline, before later waiters are woken.
+    ///
+    /// Async sleeps are always woken all at once.
+    DeadlineOrder,
+}
 
 #[derive(Debug)]
 pub struct MockClock {
     local_epoch: Instant,
+    max_elapsed: Duration,
+    system_epoch: SystemTime,
     elapsed: Mutex<Duration>,
+    advanced: Condvar,
+    wake_order: WakeOrder,
+    waiting: Mutex<Vec<Duration>>,
+    woken: Condvar,
+    wakers: Mutex<Vec<Waker>>,
+    schedule: Mutex<CallbackQueue>,
+    rewindable: bool,
 }
 
 impl Default for MockClock {
     #[inline]
     fn default() -> Self {
-        #[cfg(debug_assertions)]
-        let local_epoch = StdClock::new_mock_epoch();
-
-        #[cfg(not(debug_assertions))]
-        let local_epoch = StdClock.now();
-
-        Self {
-            local_epoch,
-            elapsed: Mutex::new(Duration::ZERO),
-        }
+        Self::with_elapsed(Duration::ZERO)
     }
 }
 
@@ -35,9 +79,445 @@ impl MockClock {
         Arc::new(MockClock::new())
     }
 
+    /// Moves this clock into an [`Arc`] for sharing; the same as
+    /// `Arc::from(clock)`.
+    #[inline]
+    pub fn into_shared(self) -> Arc<MockClock> {
+        Arc::new(self)
+    }
+
+    #[inline]
+    pub fn with_elapsed(elapsed: Duration) -> Self {
+        let local_epoch = StdClock::new_mock_epoch();
+        Self {
+            local_epoch,
+            max_elapsed: local_epoch
+                .saturating_add(Duration::MAX)
+                .duration_since(local_epoch),
+            system_epoch: SystemTime::UNIX_EPOCH,
+            elapsed: Mutex::new(elapsed),
+            advanced: Condvar::new(),
+            wake_order: WakeOrder::NotifyAll,
+            waiting: Mutex::new(Vec::new()),
+            woken: Condvar::new(),
+            wakers: Mutex::new(Vec::new()),
+            schedule: Mutex::new(CallbackQueue::default()),
+            rewindable: false,
+        }
+    }
+
+    #[inline]
+    pub fn with_elapsed_shared(elapsed: Duration) -> Arc<MockClock> {
+        Arc::new(MockClock::with_elapsed(elapsed))
+    }
+
+    /// Sets the wall-clock time this clock's epoch corresponds to, which
+    /// [`system_now`](Clock::system_now) adds the elapsed time onto.
+    /// Defaults to [`SystemTime::UNIX_EPOCH`].
+    #[inline]
+    pub fn with_system_epoch(self, system_epoch: SystemTime) -> Self {
+        Self {
+            system_epoch,
+            ..self
+        }
+    }
+
+    #[inline]
+    pub fn system_epoch(&self) -> SystemTime {
+        self.system_epoch
+    }
+
+    /// Sets how advances wake blocked waiters. Defaults to
+    /// [`WakeOrder::NotifyAll`].
+    #[inline]
+    pub fn with_wake_order(self, wake_order: WakeOrder) -> Self {
+        Self { wake_order, ..self }
+    }
+
+    /// Creates a clock that can be [rewound](Self::rewind).
+    ///
+    /// Rewinding breaks the monotonicity that most code relies on, so it is
+    /// only allowed on clocks created this way, for replay and time-travel
+    /// debugging.
+    #[inline]
+    pub fn new_rewindable() -> Self {
+        Self {
+            rewindable: true,
+            ..Self::new()
+        }
+    }
+
+    /// Moves time forward by `duration`.
+    ///
+    /// Stops at the latest representable [`Instant`] rather than
+    /// overflowing; use [`try_advance`](Self::try_advance) to detect that
+    /// case.
     #[inline]
     pub fn advance(&self, duration: Duration) {
-        *self.elapsed.lock().unwrap() += duration;
+        self.advance_stepwise(duration, self.max_elapsed);
+    }
+
+    /// Advances by each of `advances` in turn, calling `after_each` after
+    /// every step.
+    #[inline]
+    pub fn apply_advances<F>(&self, advances: &[Duration], mut after_each: F)
+    where
+        F: FnMut(&MockClock),
+    {
+        for advance in advances {
+            self.advance(*advance);
+            after_each(self);
+        }
+    }
+
+    /// Like [`advance`](Self::advance), but leaves the clock untouched and
+    /// returns an error if the new time would not be representable.
+    ///
+    /// A concurrent advance can still use up the room checked for here, in
+    /// which case this saturates like `advance`.
+    #[inline]
+    pub fn try_advance(&self, duration: Duration) -> Result<(), ClockOverflow> {
+        let elapsed = self.elapsed();
+        match elapsed.checked_add(duration) {
+            Some(target) if target <= self.max_elapsed => {
+                self.advance_stepwise(duration, self.max_elapsed);
+                Ok(())
+            }
+            _ => Err(ClockOverflow {
+                elapsed,
+                by: duration,
+            }),
+        }
+    }
+
+    #[inline]
+    pub fn advance_nanos(&self, nanos: u64) {
+        self.advance(Duration::from_nanos(nanos));
+    }
+
+    #[inline]
+    pub fn advance_millis(&self, millis: u64) {
+        self.advance(Duration::from_millis(millis));
+    }
+
+    #[inline]
+    pub fn advance_secs(&self, secs: u64) {
+        self.advance(Duration::from_secs(secs));
+    }
+
+    /// Advances by `duration`, but not past `cap`.
+    ///
+    /// Returns whether the clock is now at or past `cap`. A cap that has
+    /// already passed leaves the clock where it is.
+    #[inline]
+    pub fn advance_saturating(&self, duration: Duration, cap: Instant) -> bool {
+        let cap_elapsed = cap.saturating_duration_since(self.local_epoch);
+        self.advance_stepwise(duration, cap_elapsed) >= cap_elapsed
+    }
+
+    /// Moves time forward to `elapsed` since the epoch, stopping at the
+    /// latest representable [`Instant`].
+    #[inline]
+    pub fn set_elapsed(&self, elapsed: Duration) {
+        let current = self.elapsed();
+        assert!(
+            elapsed >= current,
+            "MockClock::set_elapsed would move time backward ({elapsed:?} < {current:?})"
+        );
+        self.advance_stepwise(Duration::MAX, elapsed.min(self.max_elapsed));
+    }
+
+    #[inline]
+    pub fn advance_to(&self, target: Instant) -> Instant {
+        let target_elapsed = target - self.local_epoch;
+        debug_assert!(
+            target_elapsed >= self.elapsed(),
+            "MockClock::advance_to target precedes the current time"
+        );
+        self.local_epoch + self.advance_stepwise(Duration::MAX, target_elapsed)
+    }
+
+    /// Moves time backward by `duration`, stopping at the epoch.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless the clock was created with
+    /// [`new_rewindable`](Self::new_rewindable).
+    #[inline]
+    pub fn rewind(&self, duration: Duration) {
+        assert!(
+            self.rewindable,
+            "MockClock::rewind requires a clock created with MockClock::new_rewindable"
+        );
+
+        let mut elapsed = self.elapsed.lock().unwrap();
+        *elapsed = elapsed.saturating_sub(duration);
+    }
+
+    #[inline]
+    pub fn snapshot(&self) -> MockSnapshot {
+        MockSnapshot { at: self.now() }
+    }
+
+    /// Sets the clock back to the time captured by `snapshot` and wakes all
+    /// waiters to re-check their deadlines.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `snapshot` is from another clock, or is earlier than the
+    /// current time and the clock was not created with
+    /// [`new_rewindable`](Self::new_rewindable).
+    #[inline]
+    pub fn restore(&self, snapshot: MockSnapshot) {
+        let target = snapshot.at.duration_since(self.local_epoch);
+        let mut elapsed = self.elapsed.lock().unwrap();
+        assert!(
+            target >= *elapsed || self.rewindable,
+            "MockClock::restore to an earlier snapshot requires a clock created with MockClock::new_rewindable"
+        );
+        if target >= *elapsed {
+            drop(elapsed);
+            self.advance_stepwise(Duration::MAX, target);
+        } else {
+            *elapsed = target;
+            drop(elapsed);
+            self.notify_advanced();
+        }
+    }
+
+    #[inline]
+    pub fn elapsed(&self) -> Duration {
+        *self.elapsed.lock().unwrap()
+    }
+
+    /// Panics unless exactly `expected` has elapsed since the clock's epoch,
+    /// naming how far off it is.
+    #[track_caller]
+    #[inline]
+    pub fn assert_elapsed(&self, expected: Duration) {
+        let actual = self.elapsed();
+        if actual > expected {
+            panic!(
+                "MockClock elapsed {actual:?}, expected {expected:?} ({:?} too far)",
+                actual - expected
+            );
+        } else if actual < expected {
+            panic!(
+                "MockClock elapsed {actual:?}, expected {expected:?} ({:?} short)",
+                expected - actual
+            );
+        }
+    }
+
+    /// Registers `callback` to run once this clock is advanced to or past
+    /// `at`, or immediately if it already has been.
+    ///
+    /// Callbacks run on the thread that advances the clock, in order of `at`;
+    /// callbacks scheduled for the same instant run in the order they were
+    /// scheduled. An advance past several callbacks stops at each one, so
+    /// `now()` reads as the callback's own deadline while it runs. Callbacks
+    /// may read and schedule on this clock, but must not advance it.
+    #[inline]
+    pub fn schedule(&self, at: Instant, callback: Callback) {
+        let at = at.saturating_duration_since(self.local_epoch);
+        self.schedule.lock().unwrap().push(at, callback);
+        self.run_due_callbacks();
+    }
+
+    /// Returns the deadlines of the callbacks that have not run yet, earliest
+    /// first.
+    #[inline]
+    pub fn pending_callbacks(&self) -> Vec<Instant> {
+        self.schedule
+            .lock()
+            .unwrap()
+            .deadlines()
+            .into_iter()
+            .map(|at| self.local_epoch + at)
+            .collect()
+    }
+
+    /// Advances to each scheduled callback in turn, running it, until none
+    /// remain.
+    ///
+    /// Callbacks that keep scheduling more callbacks keep this running
+    /// forever; use [`run_until`](Self::run_until) to bound it.
+    #[inline]
+    pub fn run_until_idle(&self) {
+        loop {
+            let next = self.schedule.lock().unwrap().next_at();
+            let Some(at) = next else {
+                return;
+            };
+
+            self.advance_stepwise(Duration::MAX, at);
+        }
+    }
+
+    /// Like [`run_until_idle`](Self::run_until_idle), but stops at `limit`,
+    /// leaving later callbacks pending and the clock at `limit` unless it was
+    /// already past it.
+    #[inline]
+    pub fn run_until(&self, limit: Instant) {
+        self.advance_stepwise(Duration::MAX, limit.duration_since(self.local_epoch));
+    }
+
+    /// Moves time forward by `duration`, but not past an elapsed time of
+    /// `cap`, stopping at each scheduled callback on the way so that it
+    /// observes its own deadline as `now()`.
+    ///
+    /// Each step adds what is left of `duration` to the current time and
+    /// clamps it to `cap` under the lock, so concurrent advances still add up
+    /// and never carry this one past `cap`. Returns the elapsed time this
+    /// advance stopped at.
+    fn advance_stepwise(&self, mut remaining: Duration, cap: Duration) -> Duration {
+        loop {
+            let mut elapsed = self.elapsed.lock().unwrap();
+            let target = elapsed.saturating_add(remaining).min(cap).max(*elapsed);
+            let next_callback = self.schedule.lock().unwrap().next_at();
+            let next_waiter = self.next_waiter_after(*elapsed);
+            let step = match next_callback.into_iter().chain(next_waiter).min() {
+                Some(at) if at < target => at.max(*elapsed),
+                _ => target,
+            };
+            remaining = remaining.saturating_sub(step - *elapsed);
+            *elapsed = step;
+            drop(elapsed);
+
+            self.notify_advanced();
+            self.wait_for_waiters_due_by(step);
+            if step == target {
+                return step;
+            }
+        }
+    }
+
+    fn next_waiter_after(&self, elapsed: Duration) -> Option<Duration> {
+        if self.wake_order != WakeOrder::DeadlineOrder {
+            return None;
+        }
+
+        let waiting = self.waiting.lock().unwrap();
+        waiting.iter().copied().filter(|&at| at > elapsed).min()
+    }
+
+    fn wait_for_waiters_due_by(&self, elapsed: Duration) {
+        if self.wake_order != WakeOrder::DeadlineOrder {
+            return;
+        }
+
+        let waiting = self.waiting.lock().unwrap();
+        let _waiting = self
+            .woken
+            .wait_while(waiting, |waiting| waiting.iter().any(|&at| at <= elapsed))
+            .unwrap();
+    }
+
+    /// Records a thread blocked until `deadline`, so that advances in
+    /// [`WakeOrder::DeadlineOrder`] stop there; dropping the returned guard
+    /// removes it.
+    fn register_waiter(&self, deadline: Instant) -> Option<Waiter<'_>> {
+        if self.wake_order != WakeOrder::DeadlineOrder {
+            return None;
+        }
+
+        let at = deadline.saturating_duration_since(self.local_epoch);
+        self.waiting.lock().unwrap().push(at);
+        Some(Waiter { clock: self, at })
+    }
+
+    #[inline]
+    fn notify_advanced(&self) {
+        self.advanced.notify_all();
+        for waker in std::mem::take(&mut *self.wakers.lock().unwrap()) {
+            waker.wake();
+        }
+        self.run_due_callbacks();
+    }
+
+    /// Returns whether `deadline` has been reached, registering `waker` to be
+    /// woken on the next advance if not.
+    #[inline]
+    pub(crate) fn poll_deadline(&self, deadline: Instant, waker: &Waker) -> bool {
+        let elapsed = self.elapsed.lock().unwrap();
+        if self.local_epoch + *elapsed >= deadline {
+            return true;
+        }
+
+        let mut wakers = self.wakers.lock().unwrap();
+        if !wakers.iter().any(|registered| registered.will_wake(waker)) {
+            wakers.push(waker.clone());
+        }
+        false
+    }
+
+    #[inline]
+    fn run_due_callbacks(&self) {
+        loop {
+            let elapsed = self.elapsed();
+            let Some(callback) = self.schedule.lock().unwrap().pop_due(elapsed) else {
+                return;
+            };
+
+            callback();
+        }
+    }
+
+    /// Blocks until another thread advances this clock to or past `deadline`.
+    #[inline]
+    pub fn wait_until(&self, deadline: Instant) {
+        let _waiter = self.register_waiter(deadline);
+        let _elapsed = self
+            .advanced
+            .wait_while(self.elapsed.lock().unwrap(), |elapsed| {
+                self.local_epoch + *elapsed < deadline
+            })
+            .unwrap();
+    }
+
+    /// Like [`wait_until`](Self::wait_until), but gives up after
+    /// `real_timeout` of wall-clock time.
+    ///
+    /// Returns whether the clock reached `deadline`.
+    #[inline]
+    pub fn wait_until_or_timeout(&self, deadline: Instant, real_timeout: Duration) -> bool {
+        let _waiter = self.register_waiter(deadline);
+        let (_elapsed, result) = self
+            .advanced
+            .wait_timeout_while(self.elapsed.lock().unwrap(), real_timeout, |elapsed| {
+                self.local_epoch + *elapsed < deadline
+            })
+            .unwrap();
+        !result.timed_out()
+    }
+}
+
+struct Waiter<'a> {
+    clock: &'a MockClock,
+    at: Duration,
+}
+
+impl Drop for Waiter<'_> {
+    fn drop(&mut self) {
+        let mut waiting = self.clock.waiting.lock().unwrap();
+        if let Some(i) = waiting.iter().position(|&at| at == self.at) {
+            waiting.swap_remove(i);
+        }
+        drop(waiting);
+        self.clock.woken.notify_all();
+    }
+}
+
+/// A point in a [`MockClock`]'s timeline, for [`MockClock::restore`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MockSnapshot {
+    at: Instant,
+}
+
+impl MockSnapshot {
+    #[inline]
+    pub const fn instant(&self) -> Instant {
+        self.at
     }
 }
 
@@ -46,6 +526,16 @@ impl Clock for MockClock {
     fn now(&self) -> Instant {
         self.local_epoch + *self.elapsed.lock().unwrap()
     }
+
+    #[inline]
+    fn system_now(&self) -> SystemTime {
+        self.system_epoch + self.elapsed()
+    }
+
+    #[inline]
+    fn sleep_until(&self, deadline: Instant) {
+        self.wait_until(deadline);
+    }
 }
 
 #[allow(unused)]
@@ -136,18 +626,18 @@ mod tests {
         let t1 = std::thread::spawn(move || {
             let true_start = std::time::Instant::now();
             let start = arc_mock_clone.now();
-            loop {
+            for _ in 0..8 {
                 println!("True elapsed: {:?}", true_start.elapsed());
                 println!("Elapsed: {:?}", arc_mock_clone.elapsed_since(start));
                 tx_ready.send(()).unwrap();
-                let _ = rx_update.recv().unwrap();
+                rx_update.recv().unwrap();
             }
         });
 
         let t2 = std::thread::spawn(move || {
             let mut count = 0;
-            loop {
-                let _ = rx_ready.recv().unwrap();
+            for _ in 0..8 {
+                rx_ready.recv().unwrap();
                 count = match count {
                     0..3 => count + 1,
                     3 => {
@@ -164,7 +654,7 @@ mod tests {
 
         let uses = UsesClock::with_mock_clock(Arc::clone(&clk));
 
-        loop {
+        for _ in 0..4 {
             println!("{:?}", uses.use_clock());
             clk.advance(Duration::from_secs(1));
         }
@@ -173,7 +663,388 @@ mod tests {
         t2.join().unwrap();
     }
 
-    #[cfg(debug_assertions)]
+    #[test]
+    fn set_elapsed_jumps_to_absolute_offset() {
+        let clock = MockClock::new();
+        let start = clock.now();
+
+        clock.set_elapsed(Duration::from_secs(5));
+        assert_eq!(clock.elapsed(), Duration::from_secs(5));
+        assert_eq!(clock.now() - start, Duration::from_secs(5));
+
+        clock.set_elapsed(Duration::from_millis(5001));
+        assert_eq!(clock.elapsed(), Duration::from_millis(5001));
+
+        clock.set_elapsed(Duration::from_millis(5001));
+        assert_eq!(clock.elapsed(), Duration::from_millis(5001));
+    }
+
+    #[test]
+    fn sleep_parks_until_advanced() {
+        let clock = MockClock::new_shared();
+        let deadline = clock.now() + Duration::from_secs(3);
+
+        let sleeper = {
+            let clock = Arc::clone(&clock);
+            std::thread::spawn(move || {
+                clock.sleep_until(deadline);
+                clock.now()
+            })
+        };
+
+        clock.advance(Duration::from_secs(1));
+        clock.set_elapsed(Duration::from_secs(2));
+        clock.advance(Duration::from_secs(2));
+
+        assert!(sleeper.join().unwrap() >= deadline);
+    }
+
+    #[test]
+    fn deadline_order_wakes_earlier_waiters_first() {
+        let clock = Arc::new(MockClock::new().with_wake_order(WakeOrder::DeadlineOrder));
+        let start = clock.now();
+
+        let waiters: Vec<_> = [3, 1]
+            .map(|secs| {
+                let clock = Arc::clone(&clock);
+                std::thread::spawn(move || clock.wait_until(start + Duration::from_secs(secs)))
+            })
+            .into();
+        while clock.waiting.lock().unwrap().len() < 2 {
+            std::thread::yield_now();
+        }
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let observer = Arc::clone(&clock);
+        clock.schedule(
+            start + Duration::from_secs(2),
+            Box::new(move || tx.send(observer.waiting.lock().unwrap().clone()).unwrap()),
+        );
+        clock.advance(Duration::from_secs(5));
+
+        assert_eq!(rx.recv().unwrap(), [Duration::from_secs(3)]);
+        for waiter in waiters {
+            waiter.join().unwrap();
+        }
+        assert!(clock.waiting.lock().unwrap().is_empty());
+        clock.assert_elapsed(Duration::from_secs(5));
+    }
+
+    #[test]
+    fn wait_until_hands_off_between_threads() {
+        let clock = MockClock::new_shared();
+        let start = clock.now();
+
+        let waiter = {
+            let clock = Arc::clone(&clock);
+            std::thread::spawn(move || {
+                for secs in 1..=3 {
+                    let deadline = start + Duration::from_secs(secs);
+                    assert!(clock.wait_until_or_timeout(deadline, Duration::from_secs(10)));
+                    assert!(clock.elapsed_since(start) >= Duration::from_secs(secs));
+                }
+            })
+        };
+
+        for _ in 0..3 {
+            clock.advance(Duration::from_secs(1));
+        }
+
+        waiter.join().unwrap();
+
+        clock.wait_until(start);
+    }
+
+    #[test]
+    fn advance_saturating_stops_at_cap() {
+        let clock = MockClock::new();
+        let cap = clock.now() + Duration::from_secs(5);
+
+        assert!(!clock.advance_saturating(Duration::from_secs(2), cap));
+        assert!(!clock.advance_saturating(Duration::from_secs(2), cap));
+        assert!(clock.advance_saturating(Duration::from_secs(2), cap));
+        assert_eq!(clock.now(), cap);
+
+        assert!(clock.advance_saturating(Duration::from_secs(2), cap));
+        assert_eq!(clock.elapsed(), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn into_shared() {
+        let clock = MockClock::with_elapsed(Duration::from_secs(1)).into_shared();
+        let converted: Arc<MockClock> = MockClock::new().into();
+
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(clock.elapsed(), Duration::from_secs(2));
+        assert_eq!(converted.elapsed(), Duration::ZERO);
+    }
+
+    #[test]
+    fn rewind_moves_back_to_epoch() {
+        let clock = MockClock::new_rewindable();
+        clock.advance(Duration::from_secs(5));
+
+        clock.rewind(Duration::from_secs(2));
+        assert_eq!(clock.elapsed(), Duration::from_secs(3));
+
+        clock.rewind(Duration::from_secs(10));
+        assert_eq!(clock.elapsed(), Duration::ZERO);
+    }
+
+    #[test]
+    fn restore_snapshot() {
+        let clock = MockClock::new_rewindable();
+        clock.advance(Duration::from_secs(1));
+        let snapshot = clock.snapshot();
+
+        clock.advance(Duration::from_secs(5));
+        clock.restore(snapshot);
+        assert_eq!(clock.now(), snapshot.instant());
+        assert_eq!(clock.elapsed(), Duration::from_secs(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "new_rewindable")]
+    fn restore_backward_requires_opt_in() {
+        let clock = MockClock::new();
+        let snapshot = clock.snapshot();
+
+        clock.advance(Duration::from_secs(1));
+        clock.restore(snapshot);
+    }
+
+    #[test]
+    #[should_panic(expected = "new_rewindable")]
+    fn rewind_requires_opt_in() {
+        MockClock::new().rewind(Duration::from_secs(1));
+    }
+
+    #[test]
+    fn wait_until_or_timeout_gives_up() {
+        let clock = MockClock::new();
+        let deadline = clock.now() + Duration::from_secs(1);
+
+        assert!(!clock.wait_until_or_timeout(deadline, Duration::from_millis(10)));
+
+        clock.advance(Duration::from_secs(1));
+        assert!(clock.wait_until_or_timeout(deadline, Duration::ZERO));
+    }
+
+    #[test]
+    fn with_elapsed_starts_aged() {
+        let clock = MockClock::with_elapsed(Duration::from_secs(3600));
+        assert_eq!(clock.elapsed(), Duration::from_secs(3600));
+
+        let shared = MockClock::with_elapsed_shared(Duration::from_secs(60));
+        shared.advance(Duration::from_secs(1));
+        assert_eq!(shared.elapsed(), Duration::from_secs(61));
+    }
+
+    #[test]
+    fn scheduled_callbacks_run_in_order() {
+        let clock = MockClock::new_shared();
+        let start = clock.now();
+        let (tx, rx) = std::sync::mpsc::channel();
+
+        for (secs, label) in [(2, "b"), (1, "a"), (2, "c"), (5, "d")] {
+            let tx = tx.clone();
+            clock.schedule(
+                start + Duration::from_secs(secs),
+                Box::new(move || tx.send(label).unwrap()),
+            );
+        }
+
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(rx.try_iter().collect::<Vec<_>>(), ["a"]);
+
+        clock.set_elapsed(Duration::from_secs(3));
+        assert_eq!(rx.try_iter().collect::<Vec<_>>(), ["b", "c"]);
+
+        clock.advance(Duration::from_secs(2));
+        assert_eq!(rx.try_iter().collect::<Vec<_>>(), ["d"]);
+    }
+
+    #[test]
+    fn pending_callbacks_lists_deadlines() {
+        let clock = MockClock::new();
+        let start = clock.now();
+
+        for secs in [3, 1, 2] {
+            clock.schedule(start + Duration::from_secs(secs), Box::new(|| {}));
+        }
+        assert_eq!(
+            clock.pending_callbacks(),
+            [1, 2, 3].map(|secs| start + Duration::from_secs(secs))
+        );
+
+        clock.advance(Duration::from_secs(2));
+        assert_eq!(clock.pending_callbacks(), [start + Duration::from_secs(3)]);
+    }
+
+    #[test]
+    fn run_until_idle_drains_chained_callbacks() {
+        let clock = MockClock::new_shared();
+        let start = clock.now();
+        let (tx, rx) = std::sync::mpsc::channel();
+
+        let (chained, tx_first) = (Arc::clone(&clock), tx.clone());
+        clock.schedule(
+            start + Duration::from_secs(1),
+            Box::new(move || {
+                tx_first.send(chained.elapsed()).unwrap();
+                let observer = Arc::clone(&chained);
+                chained.schedule(
+                    chained.now() + Duration::from_secs(2),
+                    Box::new(move || tx.send(observer.elapsed()).unwrap()),
+                );
+            }),
+        );
+
+        clock.run_until(start + Duration::from_secs(2));
+        assert_eq!(rx.try_iter().collect::<Vec<_>>(), [Duration::from_secs(1)]);
+        assert_eq!(clock.pending_callbacks(), [start + Duration::from_secs(3)]);
+        clock.assert_elapsed(Duration::from_secs(2));
+
+        clock.run_until_idle();
+        assert_eq!(rx.try_iter().collect::<Vec<_>>(), [Duration::from_secs(3)]);
+        assert!(clock.pending_callbacks().is_empty());
+        clock.assert_elapsed(Duration::from_secs(3));
+    }
+
+    #[test]
+    fn callbacks_observe_their_own_deadline() {
+        let clock = MockClock::new_shared();
+        let start = clock.now();
+        let (tx, rx) = std::sync::mpsc::channel();
+
+        for millis in [300, 100, 200] {
+            let (tx, observer) = (tx.clone(), Arc::clone(&clock));
+            clock.schedule(
+                start + Duration::from_millis(millis),
+                Box::new(move || tx.send(observer.elapsed()).unwrap()),
+            );
+        }
+
+        clock.advance(Duration::from_secs(1));
+
+        assert_eq!(
+            rx.try_iter().collect::<Vec<_>>(),
+            [100, 200, 300].map(Duration::from_millis)
+        );
+        assert_eq!(clock.elapsed(), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn advance_to_reaches_target() {
+        let clock = MockClock::new();
+        let target = clock.now() + Duration::from_millis(2500);
+
+        assert_eq!(clock.advance_to(target), target);
+        assert_eq!(clock.now(), target);
+        assert_eq!(clock.elapsed(), Duration::from_millis(2500));
+    }
+
+    #[cfg(any(debug_assertions, feature = "track-source"))]
+    #[test]
+    #[should_panic]
+    fn advance_to_other_clock_panics() {
+        let c1 = MockClock::new();
+        let c2 = MockClock::new();
+
+        c2.advance_to(c1.now() + Duration::from_secs(1));
+    }
+
+    #[test]
+    fn boxed_trait_object() {
+        struct Config {
+            clock: Box<dyn Clock>,
+        }
+
+        let mock = MockClock::new_shared();
+        let config = Config {
+            clock: Box::new(Arc::clone(&mock)),
+        };
+        let start = config.clock.now();
+
+        mock.advance(Duration::from_secs(1));
+        assert_eq!(config.clock.elapsed_since(start), Duration::from_secs(1));
+
+        let boxed: Box<dyn Clock> = Box::new(MockClock::new());
+        use_ref(&boxed);
+
+        let by_ref: &dyn Clock = &*mock;
+        use_ref(&by_ref);
+        assert_eq!(by_ref.now(), mock.now());
+    }
+
+    #[test]
+    fn borrowed_clock() {
+        fn takes_clock(clock: impl Clock) -> Instant {
+            clock.now()
+        }
+
+        let mock = MockClock::new();
+        mock.advance(Duration::from_secs(1));
+        assert_eq!(takes_clock(&mock), mock.now());
+    }
+
+    #[test]
+    fn now_with_system_follows_mock_epoch() {
+        let system_epoch = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let clock = MockClock::new().with_system_epoch(system_epoch);
+        let start = clock.now();
+
+        clock.advance_secs(90);
+        let (now, system) = clock.now_with_system();
+        assert_eq!(now, start + Duration::from_secs(90));
+        assert_eq!(system, system_epoch + Duration::from_secs(90));
+        assert_eq!(now.to_system_time(start, system_epoch), system);
+    }
+
+    #[test]
+    fn try_advance_reports_overflow() {
+        let clock = MockClock::new();
+        clock.advance_secs(1);
+
+        let overflow = clock.try_advance(Duration::MAX).unwrap_err();
+        assert_eq!(
+            (overflow.elapsed(), overflow.by()),
+            (Duration::from_secs(1), Duration::MAX)
+        );
+        clock.assert_elapsed(Duration::from_secs(1));
+
+        assert_eq!(clock.try_advance(Duration::from_secs(1)), Ok(()));
+        clock.advance(Duration::MAX);
+        assert_eq!(clock.now(), clock.local_epoch.saturating_add(Duration::MAX));
+    }
+
+    #[test]
+    fn advance_by_unit() {
+        let clock = MockClock::new();
+
+        clock.advance_secs(1);
+        clock.advance_millis(2);
+        clock.advance_nanos(3);
+        clock.assert_elapsed(Duration::new(1, 2_000_003));
+    }
+
+    #[test]
+    #[should_panic(expected = "expected 1s (1ns short)")]
+    fn assert_elapsed_reports_difference() {
+        let clock = MockClock::new();
+        clock.advance_nanos(999_999_999);
+        clock.assert_elapsed(Duration::from_secs(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_elapsed_backward_panics() {
+        let clock = MockClock::new();
+        clock.advance(Duration::from_secs(2));
+        clock.set_elapsed(Duration::from_secs(1));
+    }
+
+    #[cfg(any(debug_assertions, feature = "track-source"))]
     #[test]
     #[should_panic]
     fn panic_with_debug_assertions() {
@@ -184,7 +1055,7 @@ mod tests {
         c2.elapsed_since(c1.now());
     }
 
-    #[cfg(not(debug_assertions))]
+    #[cfg(not(any(debug_assertions, feature = "track-source")))]
     #[test]
     fn no_panic_without_debug_assertions() {
         let c1 = MockClock::new();
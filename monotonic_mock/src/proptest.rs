@@ -0,0 +1,53 @@
+//! [`proptest`] strategies for driving a
+//! [`MockClock`](crate::MockClock), whose generated sequences can be replayed
+//! with [`MockClock::apply_advances`](crate::MockClock::apply_advances).
+
+use ::proptest::collection::{SizeRange, vec};
+use ::proptest::strategy::Strategy;
+use std::time::Duration;
+
+/// Generates a single advance of at most `max_step`.
+///
+/// Advances are generated as nanosecond counts, so they shrink toward zero.
+#[inline]
+pub fn advance(max_step: Duration) -> impl Strategy<Value = Duration> + Clone {
+    let max_nanos = u64::try_from(max_step.as_nanos()).unwrap_or(u64::MAX);
+    (0..=max_nanos).prop_map(Duration::from_nanos)
+}
+
+/// Generates a sequence of advances, each at most `max_step`, whose length
+/// is in `len`.
+///
+/// Shrinks toward shorter sequences of smaller advances.
+#[inline]
+pub fn advance_sequence<L>(max_step: Duration, len: L) -> impl Strategy<Value = Vec<Duration>>
+where
+    L: Into<SizeRange>,
+{
+    vec(advance(max_step), len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MockClock;
+    use ::proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn sequences_respect_bounds(
+            advances in advance_sequence(Duration::from_millis(10), 1..20),
+        ) {
+            prop_assert!((1..20).contains(&advances.len()));
+            prop_assert!(advances.iter().all(|advance| *advance <= Duration::from_millis(10)));
+
+            let clock = MockClock::new();
+            let mut previous = clock.elapsed();
+            clock.apply_advances(&advances, |clock| {
+                assert!(clock.elapsed() >= previous);
+                previous = clock.elapsed();
+            });
+            prop_assert_eq!(clock.elapsed(), advances.iter().sum::<Duration>());
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use monotonic::{Clock, Instant, StdClock};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Replays a recorded sequence of readings, one per call to `now`.
+///
+/// Readings are given as offsets from the clock's epoch and must not
+/// decrease. Once they run out, the last one repeats; an empty trace always
+/// reads the epoch.
+#[derive(Debug)]
+pub struct ReplayClock {
+    local_epoch: Instant,
+    offsets: Vec<Duration>,
+    next: Mutex<usize>,
+}
+
+impl ReplayClock {
+    #[inline]
+    pub fn new(offsets: Vec<Duration>) -> Self {
+        assert!(
+            offsets.windows(2).all(|pair| pair[0] <= pair[1]),
+            "ReplayClock offsets must not decrease"
+        );
+
+        Self {
+            local_epoch: StdClock::new_mock_epoch(),
+            offsets,
+            next: Mutex::new(0),
+        }
+    }
+
+    /// Returns how many recorded readings have not been returned yet.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.offsets.len() - *self.next.lock().unwrap()
+    }
+}
+
+impl Clock for ReplayClock {
+    #[inline]
+    fn now(&self) -> Instant {
+        let mut next = self.next.lock().unwrap();
+        let offset = self
+            .offsets
+            .get(*next)
+            .or(self.offsets.last())
+            .copied()
+            .unwrap_or_default();
+        *next = (*next + 1).min(self.offsets.len());
+
+        self.local_epoch + offset
+    }
+
+    /// Skips recorded readings that fall before `deadline`, so the next
+    /// reading is at or past it. Returns early if the trace runs out first.
+    #[inline]
+    fn sleep_until(&self, deadline: Instant) {
+        let deadline = deadline.saturating_duration_since(self.local_epoch);
+        let mut next = self.next.lock().unwrap();
+        while *next < self.offsets.len() && self.offsets[*next] < deadline {
+            *next += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use monotonic::ClockExt;
+
+    #[test]
+    fn replays_then_repeats_last() {
+        let offsets = [0, 5, 7, 30].map(Duration::from_millis).to_vec();
+        let clock = ReplayClock::new(offsets.clone());
+        assert_eq!(clock.remaining(), 4);
+
+        let start = clock.now();
+        let readings: Vec<_> = (0..5).map(|_| clock.now() - start).collect();
+        assert_eq!(readings, [5, 7, 30, 30, 30].map(Duration::from_millis));
+        assert_eq!(clock.remaining(), 0);
+    }
+
+    #[test]
+    fn sleep_skips_to_deadline() {
+        let clock = ReplayClock::new([0, 1, 2, 3, 4].map(Duration::from_secs).to_vec());
+        let start = clock.now();
+
+        clock.sleep(Duration::from_millis(1500));
+        assert_eq!(clock.now() - start, Duration::from_secs(3));
+        assert_eq!(clock.remaining(), 1);
+    }
+}
@@ -0,0 +1,237 @@
+use crate::MockClock;
+use monotonic::{Clock, Instant};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, VecDeque};
+use std::fmt;
+use std::ops::Deref;
+use std::time::Duration;
+
+pub type Callback = Box<dyn FnOnce() + Send>;
+
+struct Entry {
+    at: Duration,
+    seq: u64,
+    callback: Callback,
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Entry {}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Entry {
+    // Reversed so the max-heap pops the earliest entry, oldest first on ties.
+    fn cmp(&self, other: &Self) -> Ordering {
+        (other.at, other.seq).cmp(&(self.at, self.seq))
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct CallbackQueue {
+    entries: BinaryHeap<Entry>,
+    next_seq: u64,
+}
+
+impl CallbackQueue {
+    pub(crate) fn push(&mut self, at: Duration, callback: Callback) {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        self.entries.push(Entry { at, seq, callback });
+    }
+
+    pub(crate) fn next_at(&self) -> Option<Duration> {
+        self.entries.peek().map(|entry| entry.at)
+    }
+
+    /// Returns the deadlines of all queued callbacks, earliest first.
+    pub(crate) fn deadlines(&self) -> Vec<Duration> {
+        let mut deadlines: Vec<_> = self.entries.iter().map(|entry| entry.at).collect();
+        deadlines.sort_unstable();
+        deadlines
+    }
+
+    pub(crate) fn pop_due(&mut self, elapsed: Duration) -> Option<Callback> {
+        if self.entries.peek()?.at > elapsed {
+            return None;
+        }
+
+        self.entries.pop().map(|entry| entry.callback)
+    }
+}
+
+impl fmt::Debug for CallbackQueue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CallbackQueue")
+            .field("pending", &self.entries.len())
+            .finish()
+    }
+}
+
+/// A scripted sequence of callbacks at offsets along a clock's timeline.
+///
+/// [`after`](Self::after) offsets are from the instant the schedule was
+/// created; [`then_after`](Self::then_after) offsets are from the previous
+/// callback's deadline. Callbacks fire in deadline order, oldest first on
+/// ties, either from [`poll`](Self::poll) or, once
+/// [`register`](Self::register)ed, as a [`MockClock`] is advanced.
+pub struct Schedule<C>
+where
+    C: Clock,
+{
+    clock: C,
+    start: Instant,
+    last: Instant,
+    pending: VecDeque<(Instant, Callback)>,
+}
+
+impl<C> Schedule<C>
+where
+    C: Clock,
+{
+    #[inline]
+    pub fn on(clock: C) -> Self {
+        let start = clock.now();
+        Self {
+            clock,
+            start,
+            last: start,
+            pending: VecDeque::new(),
+        }
+    }
+
+    #[inline]
+    pub fn after<F>(self, delay: Duration, callback: F) -> Self
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let at = self.start + delay;
+        self.push(at, Box::new(callback))
+    }
+
+    #[inline]
+    pub fn then_after<F>(self, delay: Duration, callback: F) -> Self
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let at = self.last + delay;
+        self.push(at, Box::new(callback))
+    }
+
+    #[inline]
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Runs every callback whose deadline has been reached and returns how
+    /// many ran.
+    #[inline]
+    pub fn poll(&mut self) -> usize {
+        let now = self.clock.now();
+        let mut ran = 0;
+        while self.pending.front().is_some_and(|(at, _)| *at <= now) {
+            let (_, callback) = self.pending.pop_front().unwrap();
+            callback();
+            ran += 1;
+        }
+
+        ran
+    }
+
+    fn push(mut self, at: Instant, callback: Callback) -> Self {
+        let index = self.pending.partition_point(|(pending, _)| *pending <= at);
+        self.pending.insert(index, (at, callback));
+        self.last = at;
+        self
+    }
+}
+
+impl<C> Schedule<C>
+where
+    C: Clock + Deref<Target = MockClock>,
+{
+    /// Hands every pending callback to the mock clock, to run as it is
+    /// advanced past each deadline.
+    #[inline]
+    pub fn register(self) {
+        for (at, callback) in self.pending {
+            self.clock.schedule(at, callback);
+        }
+    }
+}
+
+impl<C> fmt::Debug for Schedule<C>
+where
+    C: Clock + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Schedule")
+            .field("clock", &self.clock)
+            .field("start", &self.start)
+            .field("last", &self.last)
+            .field("pending", &self.pending.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    fn recorder() -> (
+        Arc<Mutex<Vec<&'static str>>>,
+        impl Fn(&'static str) -> Callback,
+    ) {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let push = {
+            let log = Arc::clone(&log);
+            move |name| -> Callback {
+                let log = Arc::clone(&log);
+                Box::new(move || log.lock().unwrap().push(name))
+            }
+        };
+        (log, push)
+    }
+
+    #[test]
+    fn registered_callbacks_fire_as_mock_advances() {
+        let clock = MockClock::new_shared();
+        let (log, push) = recorder();
+
+        Schedule::on(Arc::clone(&clock))
+            .after(Duration::from_secs(1), push("x"))
+            .then_after(Duration::from_secs(2), push("y"))
+            .after(Duration::from_secs(2), push("z"))
+            .register();
+
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(*log.lock().unwrap(), ["x"]);
+        clock.advance(Duration::from_secs(2));
+        assert_eq!(*log.lock().unwrap(), ["x", "z", "y"]);
+    }
+
+    #[test]
+    fn poll_fires_due_callbacks() {
+        let clock = MockClock::new_shared();
+        let (log, push) = recorder();
+
+        let mut schedule = Schedule::on(Arc::clone(&clock))
+            .after(Duration::from_secs(1), push("x"))
+            .then_after(Duration::from_secs(1), push("y"));
+        assert_eq!(schedule.poll(), 0);
+
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(schedule.poll(), 2);
+        assert_eq!(schedule.pending(), 0);
+        assert_eq!(*log.lock().unwrap(), ["x", "y"]);
+    }
+}
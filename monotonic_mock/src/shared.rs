@@ -0,0 +1,94 @@
+use crate::MockClock;
+use monotonic::{Clock, Instant};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+/// A cloneable handle to a [`MockClock`]; all clones share the same time.
+#[derive(Debug, Clone, Default)]
+pub struct SharedMockClock(Arc<MockClock>);
+
+impl SharedMockClock {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn with_elapsed(elapsed: Duration) -> Self {
+        Self(MockClock::with_elapsed_shared(elapsed))
+    }
+
+    #[inline]
+    pub fn advance(&self, duration: Duration) {
+        self.0.advance(duration);
+    }
+
+    #[inline]
+    pub fn set_elapsed(&self, elapsed: Duration) {
+        self.0.set_elapsed(elapsed);
+    }
+
+    #[inline]
+    pub fn advance_to(&self, target: Instant) -> Instant {
+        self.0.advance_to(target)
+    }
+
+    #[inline]
+    pub fn elapsed(&self) -> Duration {
+        self.0.elapsed()
+    }
+
+    #[inline]
+    pub fn as_mock(&self) -> &MockClock {
+        &self.0
+    }
+}
+
+impl From<MockClock> for SharedMockClock {
+    #[inline]
+    fn from(clock: MockClock) -> Self {
+        Self(clock.into_shared())
+    }
+}
+
+impl From<Arc<MockClock>> for SharedMockClock {
+    #[inline]
+    fn from(clock: Arc<MockClock>) -> Self {
+        Self(clock)
+    }
+}
+
+impl Clock for SharedMockClock {
+    #[inline]
+    fn now(&self) -> Instant {
+        self.0.now()
+    }
+
+    #[inline]
+    fn system_now(&self) -> SystemTime {
+        self.0.system_now()
+    }
+
+    #[inline]
+    fn sleep_until(&self, deadline: Instant) {
+        self.0.sleep_until(deadline);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clones_share_time() {
+        let clock = SharedMockClock::new();
+        let other = clock.clone();
+        let start = other.now();
+
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(other.now() - start, Duration::from_secs(1));
+
+        other.set_elapsed(Duration::from_secs(5));
+        assert_eq!(clock.elapsed(), Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,88 @@
+use crate::MockClock;
+use monotonic::{AsyncClock, Instant};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+struct Sleep<'a> {
+    clock: &'a MockClock,
+    deadline: Instant,
+}
+
+impl Future for Sleep<'_> {
+    type Output = ();
+
+    #[inline]
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.clock.poll_deadline(self.deadline, cx.waker()) {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// Sleeping futures complete once another task or thread advances the clock
+/// to or past their deadline.
+impl AsyncClock for MockClock {
+    #[inline]
+    fn sleep_until(&self, deadline: Instant) -> impl Future<Output = ()> + Send {
+        Sleep {
+            clock: self,
+            deadline,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use monotonic::Clock;
+    use std::sync::Arc;
+    use std::task::{Wake, Waker};
+    use std::thread::Thread;
+    use std::time::Duration;
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    fn block_on<F>(future: F) -> F::Output
+    where
+        F: Future,
+    {
+        let mut future = std::pin::pin!(future);
+        let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+            std::thread::park();
+        }
+    }
+
+    #[test]
+    fn sleep_completes_when_advanced() {
+        let clock = MockClock::new_shared();
+        let deadline = clock.now() + Duration::from_secs(2);
+
+        let sleeper = {
+            let clock = Arc::clone(&clock);
+            std::thread::spawn(move || {
+                block_on(AsyncClock::sleep_until(&*clock, deadline));
+                clock.now()
+            })
+        };
+
+        clock.advance(Duration::from_secs(1));
+        clock.advance(Duration::from_secs(1));
+
+        assert!(sleeper.join().unwrap() >= deadline);
+        block_on(AsyncClock::sleep(&*clock, Duration::ZERO));
+    }
+}